@@ -0,0 +1,193 @@
+use crate::prelude::*;
+use geometrid::prelude::Tile;
+use serde::{Deserialize, Serialize};
+
+/// How much of a solution a hint gives away. Later levels reveal more.
+#[derive(
+    PartialEq,
+    Debug,
+    Eq,
+    Copy,
+    Clone,
+    Serialize,
+    Deserialize,
+    Hash,
+    PartialOrd,
+    Ord,
+    strum::EnumIter,
+    strum::Display,
+)]
+pub enum HintLevel {
+    StartTile,
+    Operator,
+    PathLength,
+    NextTile,
+}
+
+impl HintLevel {
+    /// The level after this one. The next tile hint can be requested repeatedly.
+    pub fn next(&self) -> Self {
+        match self {
+            HintLevel::StartTile => HintLevel::Operator,
+            HintLevel::Operator => HintLevel::PathLength,
+            HintLevel::PathLength => HintLevel::NextTile,
+            HintLevel::NextTile => HintLevel::NextTile,
+        }
+    }
+}
+
+#[derive(PartialEq, Debug, Eq, Copy, Clone, Serialize, Deserialize, Hash)]
+pub enum Hint<const C: u8, const R: u8> {
+    /// The first tile of the shortest solution
+    StartTile(Tile<C, R>),
+    /// The first operator in the shortest solution, or none if it only uses numbers
+    Operator(Option<Rune>),
+    /// The number of tiles in the shortest solution
+    PathLength(usize),
+    /// The tile which continues the current path towards the shortest solution that begins with it
+    NextTile(Tile<C, R>),
+}
+
+impl<const C: u8, const R: u8> Hint<C, R> {
+    pub fn level(&self) -> HintLevel {
+        match self {
+            Hint::StartTile(_) => HintLevel::StartTile,
+            Hint::Operator(_) => HintLevel::Operator,
+            Hint::PathLength(_) => HintLevel::PathLength,
+            Hint::NextTile(_) => HintLevel::NextTile,
+        }
+    }
+
+    /// The tile revealed by this hint, if any
+    pub fn tile(&self) -> Option<Tile<C, R>> {
+        match self {
+            Hint::StartTile(t) | Hint::NextTile(t) => Some(*t),
+            Hint::Operator(_) | Hint::PathLength(_) => None,
+        }
+    }
+}
+
+impl<const C: u8, const R: u8, const SIZE: usize> Board<C, R, SIZE> {
+    /// Find the shortest solution for `number` which begins with `prefix`
+    pub fn find_shortest(
+        &self,
+        number: i32,
+        prefix: &[Tile<C, R>],
+    ) -> Option<FoundWord<C, R, SIZE>> {
+        let settings = SolveSettings {
+            min: number,
+            max: number,
+        };
+        settings.solve_with_prefix(self.clone(), prefix).next()
+    }
+
+    /// Get a hint for finding `number`.
+    /// The next tile hint continues from `prefix`, all other hints ignore it.
+    /// Returns none if there is no solution.
+    pub fn get_hint(
+        &self,
+        number: i32,
+        level: HintLevel,
+        prefix: &[Tile<C, R>],
+    ) -> Option<Hint<C, R>> {
+        match level {
+            HintLevel::StartTile => {
+                let word = self.find_shortest(number, &[])?;
+                word.path.first().map(|t| Hint::StartTile(*t))
+            }
+            HintLevel::Operator => {
+                let word = self.find_shortest(number, &[])?;
+                let operator = word
+                    .path
+                    .iter()
+                    .map(|t| self[*t])
+                    .find(|r| RuneType::from(*r) == RuneType::Operator);
+                Some(Hint::Operator(operator))
+            }
+            HintLevel::PathLength => {
+                let word = self.find_shortest(number, &[])?;
+                Some(Hint::PathLength(word.path.len()))
+            }
+            HintLevel::NextTile => {
+                let word = self.find_shortest(number, prefix)?;
+                word.path.get(prefix.len()).map(|t| Hint::NextTile(*t))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use ntest::test_case;
+
+    #[test_case("1+536-249", 1, 1)]
+    #[test_case("1+536-249", 7, 3)]
+    #[test_case("1+536-249", 100, 5)]
+    fn test_path_length_hint(letters: &str, number: i32, expected: usize) {
+        let board = Board::<3, 3, 9>::try_create(letters).unwrap();
+        let hint = board.get_hint(number, HintLevel::PathLength, &[]);
+
+        assert_eq!(hint, Some(Hint::PathLength(expected)))
+    }
+
+    #[test_case("1+536-249", 1)]
+    #[test_case("1+536-249", 42)]
+    #[test_case("1+536-249", 99)]
+    fn test_hints_agree_with_solution(letters: &str, number: i32) {
+        let board = Board::<3, 3, 9>::try_create(letters).unwrap();
+        let solution = board.find_shortest(number, &[]).unwrap();
+
+        assert_eq!(solution.result, number);
+        assert_eq!(
+            board.get_hint(number, HintLevel::StartTile, &[]),
+            Some(Hint::StartTile(solution.path[0]))
+        );
+        assert_eq!(
+            board.get_hint(number, HintLevel::NextTile, &[]),
+            Some(Hint::NextTile(solution.path[0]))
+        );
+
+        let Some(Hint::Operator(operator)) = board.get_hint(number, HintLevel::Operator, &[])
+        else {
+            panic!("Expected operator hint")
+        };
+        if let Some(operator) = operator {
+            assert!(solution.path.iter().any(|t| board[*t] == operator));
+        }
+    }
+
+    #[test]
+    fn test_next_tile_follows_prefix() {
+        let board = Board::<3, 3, 9>::try_create("1+536-249").unwrap();
+        let shortest = board.find_shortest(100, &[]).unwrap();
+        let prefix = &shortest.path[..2];
+
+        let Some(Hint::NextTile(next)) = board.get_hint(100, HintLevel::NextTile, prefix) else {
+            panic!("Expected next tile hint")
+        };
+
+        assert!(next.is_adjacent_to(&prefix[1]));
+        assert!(!prefix.contains(&next));
+
+        let solution = board.find_shortest(100, prefix).unwrap();
+        assert_eq!(&solution.path[..2], prefix);
+        assert_eq!(solution.path[2], next);
+        assert_eq!(board.check(&solution.path), Ok(100));
+    }
+
+    #[test]
+    fn test_no_hint_for_illegal_prefix() {
+        let board = Board::<3, 3, 9>::try_create("1+536-249").unwrap();
+        let prefix = [Tile::new_const::<0, 0>(), Tile::new_const::<2, 2>()];
+
+        assert_eq!(board.get_hint(10, HintLevel::NextTile, &prefix), None);
+    }
+
+    #[test]
+    fn test_no_hint_for_impossible_number() {
+        let board = Board::<3, 3, 9>::try_create("1+536-249").unwrap();
+
+        assert_eq!(board.get_hint(100_000, HintLevel::StartTile, &[]), None);
+    }
+}
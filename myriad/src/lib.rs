@@ -2,11 +2,14 @@ mod board;
 mod creator;
 mod difficulty;
 mod game_mode;
+mod hint;
 mod move_result;
 mod rune;
+mod score;
 mod solver;
 
 pub mod parser;
+pub mod race;
 
 pub mod prelude {
 
@@ -14,8 +17,10 @@ pub mod prelude {
     pub use crate::creator::*;
     pub use crate::difficulty::*;
     pub use crate::game_mode::*;
+    pub use crate::hint::*;
     pub use crate::move_result::*;
     pub use crate::rune::*;
+    pub use crate::score::*;
     pub use crate::solver::*;
     pub use tinyvec::*;
 }
@@ -0,0 +1,44 @@
+use serde::{Deserialize, Serialize};
+
+/// The first player to find a number in a race
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FirstFind {
+    pub number: i32,
+    pub name: String,
+}
+
+/// A message sent from a player to the race server
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type")]
+pub enum ClientMessage {
+    /// Join the room for a board, given by its canonical string
+    Join { board: String, name: String },
+    /// Submit a path, given as tile indices on the canonical board
+    Submit { path: Vec<u8> },
+}
+
+/// A message sent from the race server to players
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type")]
+pub enum ServerMessage {
+    /// Sent to a player when they join a room
+    Joined {
+        board: String,
+        /// The name the player was given, which is unique in the room
+        name: String,
+        players: Vec<String>,
+        found: Vec<FirstFind>,
+    },
+    PlayerJoined {
+        name: String,
+    },
+    PlayerLeft {
+        name: String,
+    },
+    /// A number was found for the first time
+    Found(FirstFind),
+    /// The last message from this player was not accepted
+    Rejected {
+        reason: String,
+    },
+}
@@ -0,0 +1,169 @@
+use crate::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// Points for each tile in the shortest path to a number, so harder numbers are worth more
+pub const POINTS_PER_TILE: u32 = 10;
+/// Extra points, as a percentage, for finding a number with its shortest path
+pub const OPTIMAL_BONUS_PERCENT: u32 = 50;
+/// The share of the score lost by cheating
+pub const CHEAT_PENALTY_PERCENT: u32 = 50;
+/// The share of the score lost for each number which was hinted
+pub const HINT_PENALTY_PERCENT: u32 = 5;
+/// The most that can be lost for hints
+pub const MAX_HINT_PENALTY_PERCENT: u32 = 50;
+/// Games played within this time lose nothing for time taken
+pub const PENALTY_FREE_MILLISECONDS: u64 = 10 * 60 * 1000;
+/// The share of the score lost for each whole minute over the penalty free time
+pub const TIME_PENALTY_PERCENT_PER_MINUTE: u32 = 1;
+/// The most that can be lost for time taken
+pub const MAX_TIME_PENALTY_PERCENT: u32 = 50;
+
+/// Points for a game, so players who find the same numbers can still be compared
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct Score {
+    /// Points for the numbers found, weighted by difficulty
+    pub points: u32,
+    /// Extra points for numbers found with their shortest path
+    pub optimal_bonus: u32,
+    /// Points lost for cheating, for hints and for taking a long time
+    pub penalty: u32,
+}
+
+impl Score {
+    pub fn total(&self) -> u32 {
+        (self.points + self.optimal_bonus).saturating_sub(self.penalty)
+    }
+
+    /// The score for one number, given the length of its shortest path and of the path actually used
+    pub fn for_number(difficulty: Difficulty, path_length: usize) -> Self {
+        let shortest = difficulty.0.get();
+        let points = shortest as u32 * POINTS_PER_TILE;
+        let optimal_bonus = if path_length <= shortest as usize {
+            points * OPTIMAL_BONUS_PERCENT / 100
+        } else {
+            0
+        };
+
+        Self {
+            points,
+            optimal_bonus,
+            penalty: 0,
+        }
+    }
+
+    /// The score for a game from the difficulty and path length of each number found.
+    /// Time is penalized whether or not the game is finished, so stopping early gains nothing.
+    pub fn create(
+        found: impl IntoIterator<Item = (Difficulty, usize)>,
+        cheated: bool,
+        hints: usize,
+        elapsed_milliseconds: Option<u64>,
+    ) -> Self {
+        let score = found
+            .into_iter()
+            .map(|(difficulty, path_length)| Self::for_number(difficulty, path_length))
+            .fold(Self::default(), |acc, x| acc + x);
+
+        let percent = Self::penalty_percent(cheated, hints, elapsed_milliseconds);
+        Self {
+            penalty: (score.points + score.optimal_bonus) * percent / 100,
+            ..score
+        }
+    }
+
+    /// The share of the score lost, as a percentage
+    pub fn penalty_percent(cheated: bool, hints: usize, elapsed_milliseconds: Option<u64>) -> u32 {
+        let cheat = if cheated { CHEAT_PENALTY_PERCENT } else { 0 };
+        let hint = (hints.min(u32::MAX as usize) as u32)
+            .saturating_mul(HINT_PENALTY_PERCENT)
+            .min(MAX_HINT_PENALTY_PERCENT);
+        let time = elapsed_milliseconds
+            .map(|milliseconds| {
+                let minutes_over = milliseconds.saturating_sub(PENALTY_FREE_MILLISECONDS) / 60_000;
+                (minutes_over.min(u32::MAX as u64) as u32)
+                    .saturating_mul(TIME_PENALTY_PERCENT_PER_MINUTE)
+                    .min(MAX_TIME_PENALTY_PERCENT)
+            })
+            .unwrap_or_default();
+
+        (cheat + hint + time).min(100)
+    }
+}
+
+impl std::ops::Add for Score {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self {
+            points: self.points + rhs.points,
+            optimal_bonus: self.optimal_bonus + rhs.optimal_bonus,
+            penalty: self.penalty + rhs.penalty,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::num::NonZeroU8;
+
+    fn difficulty(tiles: u8) -> Difficulty {
+        Difficulty(NonZeroU8::new(tiles).unwrap())
+    }
+
+    #[test]
+    fn test_harder_numbers_are_worth_more() {
+        let easy = Score::for_number(difficulty(1), 1);
+        let hard = Score::for_number(difficulty(7), 7);
+
+        assert_eq!(easy.total(), 15);
+        assert_eq!(hard.total(), 105);
+    }
+
+    #[test]
+    fn test_optimal_bonus() {
+        assert_eq!(
+            Score::for_number(difficulty(4), 4),
+            Score {
+                points: 40,
+                optimal_bonus: 20,
+                penalty: 0
+            }
+        );
+        assert_eq!(Score::for_number(difficulty(4), 6).optimal_bonus, 0);
+    }
+
+    #[test]
+    fn test_penalties() {
+        let found = [(difficulty(2), 2), (difficulty(4), 5)];
+
+        assert_eq!(Score::create(found, false, 0, None).total(), 70);
+        assert_eq!(Score::create(found, true, 0, None).total(), 35);
+        assert_eq!(
+            Score::create(found, false, 0, Some(PENALTY_FREE_MILLISECONDS)).total(),
+            70
+        );
+        assert_eq!(
+            Score::create(
+                found,
+                false,
+                0,
+                Some(PENALTY_FREE_MILLISECONDS + 10 * 60_000)
+            )
+            .penalty,
+            7
+        );
+        assert_eq!(Score::create(found, false, 2, None).penalty, 7);
+
+        assert_eq!(
+            Score::penalty_percent(false, 0, Some(u64::MAX)),
+            MAX_TIME_PENALTY_PERCENT
+        );
+        assert_eq!(
+            Score::penalty_percent(false, usize::MAX, None),
+            MAX_HINT_PENALTY_PERCENT
+        );
+        assert_eq!(Score::penalty_percent(true, 0, Some(u64::MAX)), 100);
+        assert_eq!(Score::create(found, true, 0, Some(u64::MAX)).total(), 0);
+    }
+}
@@ -4,7 +4,7 @@ use geometrid::prelude::Tile;
 use itertools::Itertools;
 use serde::{Deserialize, Serialize};
 use std::{
-    collections::{HashSet, VecDeque},
+    collections::{BTreeSet, HashSet, VecDeque},
     num::NonZeroU8,
 };
 use tinyvec::ArrayVec;
@@ -30,6 +30,66 @@ impl SolveSettings {
         SolutionIter::new(board, self)
     }
 
+    ///Get all solutions to this board within the range which begin with the given prefix.
+    ///Returns no solutions if the prefix is not a legal path.
+    pub fn solve_with_prefix<const C: u8, const R: u8, const SIZE: usize>(
+        self,
+        board: Board<C, R, SIZE>,
+        prefix: &[Tile<C, R>],
+    ) -> impl Iterator<Item = FoundWord<C, R, SIZE>> {
+        SolutionIter::new_with_prefix(board, self, prefix)
+    }
+
+    ///Get every path to a number within the range, including each different path to the same number.
+    pub fn solve_all_paths<const C: u8, const R: u8, const SIZE: usize>(
+        self,
+        board: Board<C, R, SIZE>,
+    ) -> impl Iterator<Item = FoundWord<C, R, SIZE>> {
+        SolutionIter {
+            all_paths: true,
+            ..SolutionIter::new(board, self)
+        }
+    }
+
+    ///For each tile which can legally continue the prefix, get all the numbers within the range which can be reached through it.
+    ///Tiles through which no numbers can be reached are not returned.
+    pub fn continuations<const C: u8, const R: u8, const SIZE: usize>(
+        self,
+        board: &Board<C, R, SIZE>,
+        prefix: &[Tile<C, R>],
+    ) -> Vec<Continuation<C, R>> {
+        let Some(path) = Path::<C, R, SIZE>::try_from_tiles(prefix) else {
+            return vec![];
+        };
+
+        let next_tiles: Vec<Tile<C, R>> = match path.tiles.last() {
+            Some(last) => last
+                .iter_adjacent()
+                .filter(|x| !path.used.get_bit(x))
+                .collect(),
+            None => Tile::iter_by_row().collect(),
+        };
+
+        next_tiles
+            .into_iter()
+            .filter_map(|tile| {
+                let mut new_prefix = path.tiles;
+                new_prefix.push(tile);
+
+                let results: BTreeSet<i32> = self
+                    .solve_with_prefix(board.clone(), &new_prefix)
+                    .map(|x| x.result)
+                    .collect();
+
+                if results.is_empty() {
+                    None
+                } else {
+                    Some(Continuation { tile, results })
+                }
+            })
+            .collect()
+    }
+
     pub fn total_solutions(&self) -> usize {
         (self.max - self.min + 1) as usize
     }
@@ -47,6 +107,65 @@ pub struct Path<const C: u8, const R: u8, const SIZE: usize> {
     used: TileSet16<C, R, SIZE>,
 }
 
+impl<const C: u8, const R: u8, const SIZE: usize> Path<C, R, SIZE> {
+    ///Create a path from a list of tiles.
+    ///Returns none if tiles are repeated or consecutive tiles are not adjacent
+    pub fn try_from_tiles(tiles: &[Tile<C, R>]) -> Option<Self> {
+        let mut path = Self::default();
+
+        for tile in tiles {
+            if path.used.get_bit(tile) || path.tiles.len() >= SIZE {
+                return None;
+            }
+            if let Some(last) = path.tiles.last() {
+                if !last.is_adjacent_to(tile) {
+                    return None;
+                }
+            }
+            path.tiles.push(*tile);
+            path.used.set_bit(tile, true);
+        }
+
+        Some(path)
+    }
+}
+
+///A tile which can be added to a path and the numbers which can be reached by doing so
+#[derive(PartialEq, Eq, Debug, Clone, Serialize, Deserialize)]
+pub struct Continuation<const C: u8, const R: u8> {
+    pub tile: Tile<C, R>,
+    pub results: BTreeSet<i32>,
+}
+
+impl<const C: u8, const R: u8> Continuation<C, R> {
+    ///Group the paths which continue the prefix by the tile that comes next.
+    ///Given every path on a board, this gives the same results as `SolveSettings::continuations` without solving again.
+    pub fn from_paths<'a, const SIZE: usize>(
+        paths: impl IntoIterator<Item = &'a FoundWord<C, R, SIZE>>,
+        prefix: &[Tile<C, R>],
+    ) -> Vec<Self> {
+        let mut continuations: Vec<Self> = vec![];
+        for word in paths {
+            if !word.path.starts_with(prefix) {
+                continue;
+            }
+            let Some(tile) = word.path.get(prefix.len()) else {
+                continue;
+            };
+            match continuations.iter_mut().find(|x| x.tile == *tile) {
+                Some(continuation) => {
+                    continuation.results.insert(word.result);
+                }
+                None => continuations.push(Self {
+                    tile: *tile,
+                    results: BTreeSet::from([word.result]),
+                }),
+            }
+        }
+        continuations
+    }
+}
+
 #[derive(PartialEq, Eq, Debug, Clone, Serialize, Deserialize)]
 pub struct FoundWord<const C: u8, const R: u8, const SIZE: usize> {
     pub result: i32,
@@ -81,6 +200,8 @@ impl FoundWord<3, 3, 9> {
 
 struct SolutionIter<const C: u8, const R: u8, const SIZE: usize> {
     results: HashSet<i32>,
+    /// Return every path, not just the first to each number
+    all_paths: bool,
     settings: SolveSettings,
     queue: VecDeque<Path<C, R, SIZE>>,
     board: Board<C, R, SIZE>,
@@ -90,12 +211,29 @@ impl<const C: u8, const R: u8, const SIZE: usize> SolutionIter<C, R, SIZE> {
     pub fn new(board: Board<C, R, SIZE>, settings: SolveSettings) -> Self {
         Self {
             results: Default::default(),
+            all_paths: false,
             queue: VecDeque::from(vec![Default::default()]),
             board,
             settings,
         }
     }
 
+    pub fn new_with_prefix(
+        board: Board<C, R, SIZE>,
+        settings: SolveSettings,
+        prefix: &[Tile<C, R>],
+    ) -> Self {
+        let queue = Path::try_from_tiles(prefix).into_iter().collect();
+
+        Self {
+            results: Default::default(),
+            all_paths: false,
+            queue,
+            board,
+            settings,
+        }
+    }
+
     fn add_to_queue(&mut self, path: Path<C, R, SIZE>) {
         if let Some(last) = path.tiles.last() {
             for adjacent in last.iter_adjacent().filter(|x| !path.used.get_bit(x)) {
@@ -127,7 +265,8 @@ impl<const C: u8, const R: u8, const SIZE: usize> Iterator for SolutionIter<C, R
             match check_result {
                 Ok(i) => {
                     self.add_to_queue(path.clone());
-                    let should_return = self.settings.allow(i) && self.results.insert(i);
+                    let should_return =
+                        self.settings.allow(i) && (self.all_paths || self.results.insert(i));
 
                     if should_return {
                         let found_word = FoundWord {
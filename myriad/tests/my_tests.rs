@@ -95,3 +95,75 @@ pub fn test_type_sizes() {
     println!("Size of coordinate: {coordinate}");
     println!("Size of board: {board}");
 }
+
+#[test_case("1+536-249")]
+#[test_case("XCL+X-VII")]
+fn test_continuations_cover_solutions(letters: &str) {
+    let board = Board::<3, 3, 9>::try_create(letters).expect("board should be created");
+    let settings = SolveSettings { min: 1, max: 100 };
+
+    let continuations = settings.continuations(&board, &[]);
+    let reachable: std::collections::BTreeSet<i32> = continuations
+        .iter()
+        .flat_map(|x| x.results.iter().copied())
+        .collect();
+    let solutions: std::collections::BTreeSet<i32> =
+        settings.solve(board.clone()).map(|x| x.result).collect();
+
+    assert_eq!(reachable, solutions);
+}
+
+#[test]
+fn test_continuations_from_prefix() {
+    let board = Board::<3, 3, 9>::try_create("1+536-249").expect("board should be created");
+    let settings = SolveSettings { min: 1, max: 100 };
+    let prefix = [Tile::new_const::<1, 1>()];
+
+    let continuations = settings.continuations(&board, &prefix);
+
+    assert!(!continuations.is_empty());
+
+    for continuation in continuations {
+        assert!(continuation.tile.is_adjacent_to(&prefix[0]));
+
+        for result in continuation.results {
+            let word = settings
+                .solve_with_prefix(board.clone(), &[prefix[0], continuation.tile])
+                .find(|x| x.result == result)
+                .expect("result should be reachable");
+            assert_eq!(board.check(&word.path), Ok(result));
+        }
+    }
+}
+
+#[test]
+fn test_no_continuations_from_illegal_prefix() {
+    let board = Board::<3, 3, 9>::try_create("1+536-249").expect("board should be created");
+    let settings = SolveSettings { min: 1, max: 100 };
+    let prefix = [Tile::new_const::<0, 0>(), Tile::new_const::<0, 0>()];
+
+    assert!(settings.continuations(&board, &prefix).is_empty());
+}
+
+#[test_case("1+536-249")]
+#[test_case("-3271+963")]
+fn test_continuations_from_paths_match_continuations(letters: &str) {
+    let board = Board::<3, 3, 9>::try_create(letters).expect("board should be created");
+    let settings = SolveSettings { min: 1, max: 100 };
+    let paths = settings.solve_all_paths(board.clone()).collect::<Vec<_>>();
+
+    let prefixes = [
+        vec![],
+        vec![Tile::new_const::<1, 1>()],
+        vec![Tile::new_const::<0, 0>(), Tile::new_const::<1, 0>()],
+    ];
+    for prefix in prefixes {
+        let expected = settings.continuations(&board, &prefix);
+        let actual = Continuation::from_paths(paths.iter(), &prefix);
+
+        assert_eq!(actual.len(), expected.len());
+        for continuation in expected {
+            assert!(actual.contains(&continuation), "{continuation:?}");
+        }
+    }
+}
@@ -0,0 +1,108 @@
+use crate::state::prelude::*;
+use myriad::prelude::*;
+use std::rc::Rc;
+use yewdux::prelude::*;
+
+/// How a screen reader should say a rune
+pub fn spoken_rune(rune: Rune, language: Language) -> String {
+    match rune {
+        Rune::Plus => language.translate(Text::SpokenPlus).to_string(),
+        Rune::Times => language.translate(Text::SpokenTimes).to_string(),
+        Rune::Minus => language.translate(Text::SpokenMinus).to_string(),
+        Rune::Divide => language.translate(Text::SpokenDivide).to_string(),
+        Rune::Blank => language.translate(Text::SpokenBlank).to_string(),
+        Rune::RomanOne
+        | Rune::RomanFive
+        | Rune::RomanTen
+        | Rune::RomanFifty
+        | Rune::RomanOneHundred => language.translate_with(Text::SpokenRoman, &[&rune]),
+        _ => rune.to_string(),
+    }
+}
+
+/// Spell out the operators in an equation so screen readers don't skip them
+pub fn spoken_equation(text: &str, language: Language) -> String {
+    let mut result = String::new();
+    for c in text.chars() {
+        match Rune::try_from(c) {
+            Ok(rune) if RuneType::from(rune) == RuneType::Operator => {
+                result.push(' ');
+                result.push_str(spoken_rune(rune, language).as_str());
+                result.push(' ');
+            }
+            _ => result.push(c),
+        }
+    }
+    result.trim().to_string()
+}
+
+impl FoundWordType {
+    /// What to tell screen reader users when this number is made
+    pub fn announcement(&self, number: i32, language: Language) -> String {
+        let text = match self {
+            FoundWordType::Found => Text::FoundNumber,
+            FoundWordType::PreviouslyFound => Text::AlreadyFound,
+            FoundWordType::NotInRange => Text::OutOfRange,
+            FoundWordType::NotShortest => Text::NotShortest,
+        };
+        language.translate_with(text, &[&number])
+    }
+}
+
+/// Text for screen readers to speak immediately
+#[derive(PartialEq, Eq, Clone, Default, Store)]
+pub struct AnnouncementState {
+    pub text: String,
+    /// Changes with every announcement so the same text is spoken again
+    pub count: usize,
+}
+
+impl AnnouncementState {
+    pub fn live_text(&self) -> String {
+        // A trailing non-breaking space changes the text without changing what is said
+        if self.count % 2 == 0 {
+            self.text.clone()
+        } else {
+            format!("{}\u{00A0}", self.text)
+        }
+    }
+}
+
+pub struct AnnounceMsg(pub String);
+
+impl Reducer<AnnouncementState> for AnnounceMsg {
+    fn apply(self, state: Rc<AnnouncementState>) -> Rc<AnnouncementState> {
+        AnnouncementState {
+            text: self.0,
+            count: state.count + 1,
+        }
+        .into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_spoken_equation() {
+        let english = Language::English;
+        assert_eq!(spoken_equation("12+3", english), "12 plus 3");
+        assert_eq!(spoken_equation("2×3-4", english), "2 times 3 minus 4");
+        assert_eq!(spoken_equation("-8÷2", english), "minus 8 divided by 2");
+        assert_eq!(
+            spoken_equation("-8÷2", Language::Spanish),
+            "menos 8 dividido por 2"
+        );
+    }
+
+    #[test]
+    fn test_repeated_announcements_change_text() {
+        let state: Rc<AnnouncementState> = Default::default();
+        let first = AnnounceMsg("Found 7".to_string()).apply(state);
+        let second = AnnounceMsg("Found 7".to_string()).apply(first.clone());
+
+        assert_ne!(first.live_text(), second.live_text());
+        assert_eq!(first.live_text().trim(), second.live_text().trim());
+    }
+}
@@ -0,0 +1,215 @@
+use crate::state::prelude::*;
+use crate::state::stats::Streaks;
+use chrono::NaiveDate;
+use itertools::Itertools;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::rc::Rc;
+use strum::{EnumIter, IntoEnumIterator};
+use yewdux::prelude::*;
+
+const QUICK_GAME_MILLISECONDS: u64 = 10 * 60 * 1000;
+const STREAK_DAYS: usize = 7;
+
+/// A long term goal, earned once across all games
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize, EnumIter)]
+pub enum Achievement {
+    /// Find every number in a game without cheating
+    Honest,
+    /// Find every number in a game, each with the shortest possible path
+    Optimal,
+    /// Play a daily game on seven days in a row, whichever days the games were for
+    WeekStreak,
+    /// Find every number in a game in under ten minutes
+    Speedy,
+}
+
+impl Achievement {
+    pub fn icon(self) -> &'static str {
+        match self {
+            Achievement::Honest => "💯",
+            Achievement::Optimal => "🎯",
+            Achievement::WeekStreak => "📅",
+            Achievement::Speedy => "⏱️",
+        }
+    }
+
+    pub fn name(self) -> Text {
+        match self {
+            Achievement::Honest => Text::AchievementHonest,
+            Achievement::Optimal => Text::AchievementOptimal,
+            Achievement::WeekStreak => Text::AchievementWeekStreak,
+            Achievement::Speedy => Text::AchievementSpeedy,
+        }
+    }
+
+    pub fn description(self) -> Text {
+        match self {
+            Achievement::Honest => Text::AchievementHonestDescription,
+            Achievement::Optimal => Text::AchievementOptimalDescription,
+            Achievement::WeekStreak => Text::AchievementWeekStreakDescription,
+            Achievement::Speedy => Text::AchievementSpeedyDescription,
+        }
+    }
+
+    /// Whether any of these games earns this achievement
+    pub fn is_earned(self, games: &[&FullGameState], today: NaiveDate) -> bool {
+        let mut completed = games
+            .iter()
+            .filter(|x| x.is_complete() && x.timing != GameTiming::Cheat);
+
+        match self {
+            Achievement::Honest => completed.next().is_some(),
+            Achievement::Optimal => completed.any(|x| x.is_all_optimal()),
+            Achievement::WeekStreak => {
+                Streaks::for_games(games.iter().copied(), today).longest >= STREAK_DAYS
+            }
+            Achievement::Speedy => completed.any(|x| {
+                matches!(x.timing, GameTiming::Finished { total_milliseconds } if total_milliseconds < QUICK_GAME_MILLISECONDS)
+            }),
+        }
+    }
+}
+
+/// The achievements which have been unlocked
+#[derive(PartialEq, Eq, Clone, Default, Serialize, Deserialize, Store)]
+#[store(storage = "local")]
+pub struct AchievementsState {
+    /// The date each achievement was unlocked
+    pub unlocked: BTreeMap<Achievement, NaiveDate>,
+    /// Achievements the player has not been told about yet, oldest first
+    pub unseen: Vec<Achievement>,
+}
+
+impl AchievementsState {
+    /// Unlock any achievements earned by these games
+    pub fn with_earned(&self, games: &[&FullGameState], today: NaiveDate) -> Self {
+        let mut state = self.clone();
+        for achievement in Achievement::iter() {
+            if !state.unlocked.contains_key(&achievement) && achievement.is_earned(games, today) {
+                state.unlocked.insert(achievement, today);
+                state.unseen.push(achievement);
+            }
+        }
+        state
+    }
+}
+
+/// Unlock any achievements earned by the history and the current game
+pub struct CheckAchievementsMsg;
+
+impl Reducer<AchievementsState> for CheckAchievementsMsg {
+    fn apply(self, state: Rc<AchievementsState>) -> Rc<AchievementsState> {
+        let history = Dispatch::<HistoryState>::new().get();
+        let current = Dispatch::<FullGameState>::new().get();
+        let games = history
+            .all_games_including_current(current.as_ref())
+            .collect_vec();
+
+        let new_state = state.with_earned(&games, Game::get_today_date());
+        if new_state == *state {
+            return state;
+        }
+        new_state.into()
+    }
+}
+
+/// The player has seen the oldest unlock notification
+pub struct DismissAchievementMsg;
+
+impl Reducer<AchievementsState> for DismissAchievementMsg {
+    fn apply(self, state: Rc<AchievementsState>) -> Rc<AchievementsState> {
+        if state.unseen.is_empty() {
+            return state;
+        }
+        let mut state = state.as_ref().clone();
+        state.unseen.remove(0);
+        state.into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::versioned_storage::VersionedStore;
+
+    fn date(month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2023, month, day).unwrap()
+    }
+
+    fn history() -> HistoryState {
+        HistoryState::from_json(include_str!("../../fixtures/history_state_v1.json")).unwrap()
+    }
+
+    #[test]
+    fn test_achievements_from_history() {
+        let history = history();
+        let games = history.games.iter().collect_vec();
+        let today = date(6, 1);
+
+        assert!(Achievement::Honest.is_earned(&games, today));
+        assert!(!Achievement::WeekStreak.is_earned(&games, today));
+        assert!(!Achievement::Speedy.is_earned(&games, today));
+
+        let complete = &history.games[0];
+        assert_eq!(
+            Achievement::Optimal.is_earned(&games, today),
+            complete.is_all_optimal()
+        );
+
+        let mut cheat = complete.clone();
+        cheat.timing = GameTiming::Cheat;
+        assert!(!Achievement::Honest.is_earned(&[&cheat], today));
+        assert!(!Achievement::Optimal.is_earned(&[&cheat], today));
+
+        let mut quick = complete.clone();
+        quick.timing = GameTiming::Finished {
+            total_milliseconds: 9 * 60 * 1000,
+        };
+        assert!(Achievement::Speedy.is_earned(&[&quick], today));
+    }
+
+    #[test]
+    fn test_week_streak() {
+        let game = &history().games[0];
+        let week = (1..=7)
+            .map(|day| {
+                let mut game = game.clone();
+                game.played = Some(date(6, day));
+                game
+            })
+            .collect_vec();
+        let games = week.iter().collect_vec();
+
+        assert!(Achievement::WeekStreak.is_earned(&games, date(6, 7)));
+        assert!(!Achievement::WeekStreak.is_earned(&games[1..], date(6, 7)));
+
+        let past_puzzles = (1..=7)
+            .map(|day| {
+                let mut game = game.clone();
+                game.game.date = Some(date(5, day));
+                game.played = Some(date(6, 7));
+                game
+            })
+            .collect_vec();
+        let games = past_puzzles.iter().collect_vec();
+
+        assert!(!Achievement::WeekStreak.is_earned(&games, date(6, 7)));
+    }
+
+    #[test]
+    fn test_unlocks_are_only_notified_once() {
+        let history = history();
+        let games = history.games.iter().collect_vec();
+
+        let state = AchievementsState::default().with_earned(&games, date(6, 1));
+        assert_eq!(state.unlocked.get(&Achievement::Honest), Some(&date(6, 1)));
+        assert_eq!(state.unseen.first(), Some(&Achievement::Honest));
+
+        let state = DismissAchievementMsg.apply(state.into());
+        let unseen = state.unseen.len();
+        let state = state.with_earned(&games, date(6, 2));
+        assert_eq!(state.unseen.len(), unseen);
+        assert_eq!(state.unlocked.get(&Achievement::Honest), Some(&date(6, 1)));
+    }
+}
@@ -0,0 +1,330 @@
+use crate::state::prelude::*;
+use chrono::NaiveDate;
+use myriad::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::rc::Rc;
+use yewdux::prelude::*;
+
+pub type Path = ArrayVec<[Tile<GRID_COLUMNS, GRID_ROWS>; GRID_SIZE]>;
+
+/// Something the player did which can be undone
+#[derive(PartialEq, Eq, Clone, Serialize, Deserialize, Debug)]
+pub enum GameAction {
+    /// The chosen path changed without finding anything new
+    ChoosePath { from: Path, to: Path },
+    /// A number was found, or was found again with a shorter path
+    FindWord {
+        previous: Option<FoundWord<GRID_COLUMNS, GRID_ROWS, GRID_SIZE>>,
+        word: FoundWord<GRID_COLUMNS, GRID_ROWS, GRID_SIZE>,
+        /// Actions saved before these were recorded only change the found words
+        #[serde(default)]
+        effects: FindEffects,
+    },
+}
+
+/// What else changed in the game when a word was found
+#[derive(PartialEq, Eq, Clone, Serialize, Deserialize, Default, Debug)]
+pub struct FindEffects {
+    /// The event recorded for replays
+    pub event: Option<FoundWordEvent>,
+    /// The split recorded in a time attack game
+    pub split: Option<Split>,
+    /// The timing before and after, if finding the word finished the game
+    pub finished: Option<(GameTiming, GameTiming)>,
+    /// The day recorded as when the game was played, if this was the first number found
+    #[serde(default)]
+    pub played: Option<NaiveDate>,
+}
+
+impl GameAction {
+    /// The game and chosen path from before this action
+    pub fn undo(&self, state: &FullGameState) -> (FullGameState, Path) {
+        match self {
+            GameAction::ChoosePath { from, .. } => (state.clone(), *from),
+            GameAction::FindWord {
+                previous,
+                word,
+                effects,
+            } => {
+                let mut state = state.clone();
+                state.found_words = match previous {
+                    Some(previous) => state.found_words.with_word(previous.clone()),
+                    None => state.found_words.without_word(word.result),
+                }
+                .into();
+                if let Some(event) = &effects.event {
+                    if let Some(index) = state.events.iter().rposition(|x| x == event) {
+                        state.events.remove(index);
+                    }
+                }
+                if let Some(split) = &effects.split {
+                    state.timed.splits.retain(|x| x != split);
+                }
+                if let Some((before, _)) = effects.finished {
+                    state.timing = before;
+                }
+                if effects.played.is_some() {
+                    state.played = None;
+                }
+
+                let mut path = word.path;
+                path.pop();
+                (state.with_score(), path)
+            }
+        }
+    }
+
+    /// The game and chosen path from after this action
+    pub fn redo(&self, state: &FullGameState) -> (FullGameState, Path) {
+        match self {
+            GameAction::ChoosePath { to, .. } => (state.clone(), *to),
+            GameAction::FindWord { word, effects, .. } => {
+                let mut state = state.clone();
+                state.found_words = state.found_words.with_word(word.clone()).into();
+                if let Some(event) = &effects.event {
+                    state.events.push(event.clone());
+                }
+                if let Some(split) = effects.split {
+                    state.timed.splits.push(split);
+                }
+                if let Some((_, after)) = effects.finished {
+                    state.timing = after;
+                }
+                if effects.played.is_some() {
+                    state.played = effects.played;
+                }
+
+                (state.with_score(), word.path)
+            }
+        }
+    }
+}
+
+/// The actions which can be undone and redone in a game
+#[derive(PartialEq, Eq, Clone, Serialize, Deserialize, Default, Debug)]
+pub struct ActionLog {
+    pub undo: Vec<GameAction>,
+    pub redo: Vec<GameAction>,
+}
+
+impl ActionLog {
+    /// The most actions which will be remembered
+    pub const MAX_ACTIONS: usize = 100;
+
+    /// Record a new action. This clears the actions which could be redone.
+    pub fn with_action(&self, action: GameAction) -> Self {
+        let mut undo = self.undo.clone();
+        undo.push(action);
+        if undo.len() > Self::MAX_ACTIONS {
+            undo.remove(0);
+        }
+
+        Self { undo, redo: vec![] }
+    }
+
+    /// Undo the last action, returning the game from before it and the chosen path
+    pub fn undo(&self, state: &FullGameState) -> Option<(FullGameState, Path)> {
+        let mut log = self.clone();
+        let action = log.undo.pop()?;
+        let (mut state, path) = action.undo(state);
+        log.redo.push(action);
+        state.actions = log;
+        Some((state, path))
+    }
+
+    /// Redo the last undone action, returning the game from after it and the chosen path
+    pub fn redo(&self, state: &FullGameState) -> Option<(FullGameState, Path)> {
+        let mut log = self.clone();
+        let action = log.redo.pop()?;
+        let (mut state, path) = action.redo(state);
+        log.undo.push(action);
+        state.actions = log;
+        Some((state, path))
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.undo.is_empty() && self.redo.is_empty()
+    }
+}
+
+/// Add an action to the current game's log
+pub struct RecordActionMsg(pub GameAction);
+
+impl Reducer<FullGameState> for RecordActionMsg {
+    fn apply(self, mut state: Rc<FullGameState>) -> Rc<FullGameState> {
+        let gs = Rc::make_mut(&mut state);
+        gs.actions = gs.actions.with_action(self.0);
+        state
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UndoRedoMsg {
+    Undo,
+    Redo,
+}
+
+impl Reducer<FullGameState> for UndoRedoMsg {
+    fn apply(self, state: Rc<FullGameState>) -> Rc<FullGameState> {
+        if state.timed.is_over()
+            || Dispatch::<TutorialState>::new().get().is_active()
+            || is_shared(&state.game.board)
+        {
+            return state;
+        }
+
+        let result = match self {
+            UndoRedoMsg::Undo => state.actions.undo(&state),
+            UndoRedoMsg::Redo => state.actions.redo(&state),
+        };
+        let Some((new_state, positions)) = result else {
+            return state;
+        };
+
+        Dispatch::<ChosenPositionsState>::new().set(ChosenPositionsState { positions });
+
+        new_state.into()
+    }
+}
+
+/// Whether other players have seen what was found on this board, so it can't be taken back
+fn is_shared(board: &Board<GRID_COLUMNS, GRID_ROWS, GRID_SIZE>) -> bool {
+    Dispatch::<MultiplayerState>::new().get().is_active()
+        || Dispatch::<RaceState>::new()
+            .get()
+            .for_board(board)
+            .is_some()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::versioned_storage::VersionedStore;
+
+    fn game() -> FullGameState {
+        FullGameState::from_json(include_str!("../../fixtures/full_game_state_v3.json"))
+            .unwrap()
+            .for_history()
+    }
+
+    fn word(result: i32, path: &[u8]) -> FoundWord<GRID_COLUMNS, GRID_ROWS, GRID_SIZE> {
+        FoundWord {
+            result,
+            path: path
+                .iter()
+                .map(|x| Tile::try_from_inner(*x).unwrap())
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn test_undo_and_redo_find() {
+        let before = game();
+        let found = word(46, &[7, 4]);
+        let event = FoundWordEvent {
+            word: found.clone(),
+            utc_time_milliseconds: 1686000009000,
+        };
+        let split = Split {
+            found: 7,
+            milliseconds: 9000,
+        };
+        let finished = GameTiming::Finished {
+            total_milliseconds: 9000,
+        };
+
+        let mut after = before.clone();
+        after.found_words = after.found_words.with_word(found.clone()).into();
+        after.events.push(event.clone());
+        after.timed.splits.push(split);
+        after.timing = finished;
+        after.played = NaiveDate::from_ymd_opt(2023, 6, 5);
+        after.actions = ActionLog::default().with_action(GameAction::FindWord {
+            previous: None,
+            word: found.clone(),
+            effects: FindEffects {
+                event: Some(event),
+                split: Some(split),
+                finished: Some((before.timing, finished)),
+                played: after.played,
+            },
+        });
+        let after = after.with_score();
+
+        let (undone, path) = after.actions.undo(&after).unwrap();
+        assert!(undone.found_words == before.found_words);
+        assert_eq!(undone.events, before.events);
+        assert_eq!(undone.timed, before.timed);
+        assert!(undone.timing == before.timing);
+        assert_eq!(undone.played, before.played);
+        assert_eq!(undone.score, before.score);
+        assert_eq!(path.as_slice(), &found.path[..1]);
+        assert!(undone.actions.undo.is_empty());
+        assert!(undone.actions.undo(&undone).is_none());
+
+        let (redone, path) = undone.actions.redo(&undone).unwrap();
+        assert!(redone == after);
+        assert_eq!(path, found.path);
+        assert!(redone.actions.redo.is_empty());
+    }
+
+    #[test]
+    fn test_undo_shorter_path() {
+        let mut state = game();
+        let previous = state.found_words.words.get(&10).unwrap().clone();
+        let shorter = word(10, &[3, 4, 8]);
+        state.found_words = state.found_words.with_word(shorter.clone()).into();
+        state.actions = ActionLog::default().with_action(GameAction::FindWord {
+            previous: Some(previous.clone()),
+            word: shorter,
+            effects: Default::default(),
+        });
+
+        let (undone, _) = state.actions.undo(&state).unwrap();
+        assert_eq!(undone.found_words.words.get(&10), Some(&previous));
+    }
+
+    #[test]
+    fn test_undo_path() {
+        let mut state = game();
+        let from = word(0, &[0, 1]).path;
+        let to = word(0, &[0, 1, 2]).path;
+        state.actions = ActionLog::default().with_action(GameAction::ChoosePath { from, to });
+
+        let (undone, path) = state.actions.undo(&state).unwrap();
+        assert!(undone.found_words == state.found_words);
+        assert_eq!(path, from);
+
+        let (_, path) = undone.actions.redo(&undone).unwrap();
+        assert_eq!(path, to);
+    }
+
+    #[test]
+    fn test_new_action_clears_redo() {
+        let mut state = game();
+        let action = GameAction::ChoosePath {
+            from: Default::default(),
+            to: word(0, &[4]).path,
+        };
+        state.actions = ActionLog::default().with_action(action.clone());
+        let (undone, _) = state.actions.undo(&state).unwrap();
+        assert_eq!(undone.actions.redo.len(), 1);
+
+        let log = undone.actions.with_action(action);
+        assert!(log.redo.is_empty());
+        assert_eq!(log.undo.len(), 1);
+    }
+
+    #[test]
+    fn test_log_is_capped() {
+        let action = GameAction::ChoosePath {
+            from: Default::default(),
+            to: Default::default(),
+        };
+        let log = (0..ActionLog::MAX_ACTIONS + 10).fold(ActionLog::default(), |log, _| {
+            log.with_action(action.clone())
+        });
+
+        assert_eq!(log.undo.len(), ActionLog::MAX_ACTIONS);
+    }
+}
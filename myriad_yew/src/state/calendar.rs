@@ -0,0 +1,189 @@
+use crate::state::prelude::*;
+use crate::state::stats::Streaks;
+use chrono::{Datelike, NaiveDate};
+use itertools::Itertools;
+use std::collections::BTreeMap;
+
+/// The earliest daily puzzle shown in the calendar
+pub fn first_daily_date() -> NaiveDate {
+    NaiveDate::from_ymd_opt(2023, 1, 1).expect("Invalid first daily date")
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DayStatus {
+    /// The puzzle is not available yet, or was before the first puzzle
+    Unavailable,
+    NotPlayed,
+    Played {
+        found: usize,
+        total: usize,
+    },
+    Completed,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CalendarDay {
+    pub date: NaiveDate,
+    pub status: DayStatus,
+}
+
+/// One month of daily puzzles, arranged in weeks starting on Monday
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CalendarMonth {
+    /// The first day of the month
+    pub first: NaiveDate,
+    pub weeks: Vec<[Option<CalendarDay>; 7]>,
+    pub streaks: Streaks,
+}
+
+impl CalendarMonth {
+    pub fn create<'a>(
+        first: NaiveDate,
+        games: impl Iterator<Item = &'a FullGameState>,
+        today: NaiveDate,
+    ) -> Self {
+        let first = first.with_day(1).unwrap_or(first);
+
+        let games = games
+            .filter(|x| !x.found_words.words.is_empty())
+            .collect_vec();
+        let daily_games: BTreeMap<NaiveDate, (usize, usize)> = games
+            .iter()
+            .filter_map(|x| x.game.date.map(|date| (date, x.get_found_count())))
+            .collect();
+
+        let streaks = Streaks::for_games(games.into_iter(), today);
+
+        let days = first
+            .iter_days()
+            .take_while(|date| date.month() == first.month())
+            .map(|date| {
+                let status = if date > today || date < first_daily_date() {
+                    DayStatus::Unavailable
+                } else {
+                    match daily_games.get(&date) {
+                        None => DayStatus::NotPlayed,
+                        Some((found, total)) if found >= total => DayStatus::Completed,
+                        Some((found, total)) => DayStatus::Played {
+                            found: *found,
+                            total: *total,
+                        },
+                    }
+                };
+                CalendarDay { date, status }
+            });
+
+        let padding = first.weekday().num_days_from_monday() as usize;
+        let weeks = std::iter::repeat(None)
+            .take(padding)
+            .chain(days.map(Some))
+            .chunks(7)
+            .into_iter()
+            .map(|week| {
+                let mut days = [None; 7];
+                for (slot, day) in days.iter_mut().zip(week) {
+                    *slot = day;
+                }
+                days
+            })
+            .collect_vec();
+
+        Self {
+            first,
+            weeks,
+            streaks,
+        }
+    }
+
+    /// The first day of the previous month, if it has any puzzles
+    pub fn previous_month(&self) -> Option<NaiveDate> {
+        let previous = self.first.pred_opt()?.with_day(1)?;
+        (previous >= first_daily_date().with_day(1)?).then_some(previous)
+    }
+
+    /// The first day of the next month, if it has any puzzles
+    pub fn next_month(&self, today: NaiveDate) -> Option<NaiveDate> {
+        let next = (self.first + chrono::Duration::days(31)).with_day(1)?;
+        (next <= today).then_some(next)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::versioned_storage::VersionedStore;
+
+    fn date(month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2023, month, day).unwrap()
+    }
+
+    #[test]
+    fn test_calendar_month() {
+        let history =
+            HistoryState::from_json(include_str!("../../fixtures/history_state_v1.json")).unwrap();
+
+        let mut first = history.games[0].clone();
+        first.played = Some(date(6, 1));
+        let mut partial = history.games[1].clone();
+        partial.game.date = Some(date(6, 2));
+        partial.played = Some(date(6, 2));
+
+        let games = [first, partial];
+        let month = CalendarMonth::create(date(6, 15), games.iter(), date(6, 3));
+
+        assert_eq!(month.first, date(6, 1));
+        // June 2023 starts on a Thursday
+        assert_eq!(month.weeks.len(), 5);
+        assert_eq!(month.weeks[0][..3], [None, None, None]);
+
+        let days = month.weeks.iter().flatten().flatten().collect_vec();
+        assert_eq!(days.len(), 30);
+        assert_eq!(days[0].status, DayStatus::Completed);
+        assert!(matches!(days[1].status, DayStatus::Played { found: 2, .. }));
+        assert_eq!(days[2].status, DayStatus::NotPlayed);
+        assert_eq!(days[3].status, DayStatus::Unavailable);
+
+        assert_eq!(
+            month.streaks,
+            Streaks {
+                current: 2,
+                longest: 2
+            }
+        );
+    }
+
+    #[test]
+    fn test_old_puzzle_played_today() {
+        let history =
+            HistoryState::from_json(include_str!("../../fixtures/history_state_v1.json")).unwrap();
+        let mut old = history.games[0].clone();
+        old.game.date = Some(date(5, 2));
+        old.played = Some(date(6, 3));
+
+        let may = CalendarMonth::create(date(5, 1), [old].iter(), date(6, 3));
+
+        let days = may.weeks.iter().flatten().flatten().collect_vec();
+        assert_eq!(days[1].status, DayStatus::Completed);
+        assert_eq!(
+            may.streaks,
+            Streaks {
+                current: 1,
+                longest: 1
+            }
+        );
+    }
+
+    #[test]
+    fn test_month_navigation() {
+        let today = date(6, 3);
+        let june = CalendarMonth::create(date(6, 1), std::iter::empty(), today);
+        assert_eq!(june.previous_month(), Some(date(5, 1)));
+        assert_eq!(june.next_month(today), None);
+
+        let may = CalendarMonth::create(date(5, 1), std::iter::empty(), today);
+        assert_eq!(may.next_month(today), Some(date(6, 1)));
+
+        let first = CalendarMonth::create(first_daily_date(), std::iter::empty(), today);
+        assert_eq!(first.previous_month(), None);
+    }
+}
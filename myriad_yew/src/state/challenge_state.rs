@@ -0,0 +1,176 @@
+use crate::state::history_code::*;
+use crate::state::info_bar_state::{InfoBarSetMessage, InfoBarState};
+use crate::state::prelude::*;
+use base64::Engine;
+use myriad::prelude::*;
+use std::collections::BTreeSet;
+use std::rc::Rc;
+use yewdux::prelude::*;
+
+/// Another player's progress on a board, received through a challenge link
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub struct Challenge {
+    pub board: Board<GRID_COLUMNS, GRID_ROWS, GRID_SIZE>,
+    pub found: BTreeSet<i32>,
+    pub total_milliseconds: Option<u64>,
+}
+
+impl Challenge {
+    pub fn from_state(state: &FullGameState) -> Self {
+        let total_milliseconds = match state.timing {
+            GameTiming::Finished { total_milliseconds } => Some(total_milliseconds),
+            _ => None,
+        };
+
+        Self {
+            board: state.game.board.clone(),
+            found: state.found_words.words.keys().copied().collect(),
+            total_milliseconds,
+        }
+    }
+
+    /// Encode the found numbers and time for use in a route
+    pub fn encode(&self) -> String {
+        let mut bytes = encode_found_numbers(self.found.iter()).to_vec();
+        if let Some(total_milliseconds) = self.total_milliseconds {
+            bytes.extend((total_milliseconds.min(u32::MAX as u64) as u32).to_le_bytes());
+        }
+        base64::engine::general_purpose::URL_SAFE_NO_PAD.encode(bytes)
+    }
+
+    pub fn decode(board: Board<GRID_COLUMNS, GRID_ROWS, GRID_SIZE>, code: &str) -> Option<Self> {
+        let bytes = base64::engine::general_purpose::URL_SAFE_NO_PAD
+            .decode(code)
+            .ok()?;
+
+        let total_milliseconds = match bytes.len() {
+            BITSET_BYTES => None,
+            len if len == BITSET_BYTES + 4 => {
+                Some(u32::from_le_bytes(bytes[BITSET_BYTES..].try_into().ok()?) as u64)
+            }
+            _ => return None,
+        };
+
+        Some(Self {
+            board,
+            found: decode_found_numbers(&bytes[..BITSET_BYTES]).collect(),
+            total_milliseconds,
+        })
+    }
+
+    /// The route for this challenge
+    pub fn route_path(&self) -> String {
+        format!(
+            "/challenge/{}/{}",
+            self.board.canonical_string(),
+            self.encode()
+        )
+    }
+
+    pub fn message(&self, language: Language) -> String {
+        let found = self.found.len();
+        let timing = self
+            .total_milliseconds
+            .map(|total_milliseconds| GameTiming::Finished { total_milliseconds });
+        match timing.and_then(|x| x.time_string()) {
+            Some(time_string) => {
+                language.translate_with(Text::ChallengeTimed, &[&found, &time_string])
+            }
+            None => language.translate_with(Text::ChallengeUntimed, &[&found]),
+        }
+    }
+}
+
+#[derive(PartialEq, Eq, Clone, Default, Store)]
+pub struct ChallengeState {
+    pub challenge: Option<Challenge>,
+}
+
+impl ChallengeState {
+    /// The challenge for this board, if there is one
+    pub fn for_board(
+        &self,
+        board: &Board<GRID_COLUMNS, GRID_ROWS, GRID_SIZE>,
+    ) -> Option<&Challenge> {
+        self.challenge.as_ref().filter(|x| x.board == *board)
+    }
+}
+
+/// Accept a challenge and tell the player about it
+pub struct SetChallengeMsg(pub Challenge);
+
+impl Reducer<ChallengeState> for SetChallengeMsg {
+    fn apply(self, state: Rc<ChallengeState>) -> Rc<ChallengeState> {
+        if state.challenge.as_ref() == Some(&self.0) {
+            return state;
+        }
+
+        Dispatch::<InfoBarState>::new().apply(InfoBarSetMessage(InfoBarState::Challenge(
+            self.0.message(current_language()),
+        )));
+
+        ChallengeState {
+            challenge: Some(self.0),
+        }
+        .into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn board() -> Board<GRID_COLUMNS, GRID_ROWS, GRID_SIZE> {
+        Board::try_create("1+536-249").unwrap()
+    }
+
+    #[test]
+    fn test_challenge_round_trip() {
+        let challenge = Challenge {
+            board: board(),
+            found: [1, 2, 3, 42, 99, 100].into_iter().collect(),
+            total_milliseconds: Some(754321),
+        };
+
+        let code = challenge.encode();
+        assert_eq!(Challenge::decode(board(), &code), Some(challenge));
+    }
+
+    #[test]
+    fn test_challenge_without_time() {
+        let challenge = Challenge {
+            board: board(),
+            found: (1..=73).collect(),
+            total_milliseconds: None,
+        };
+
+        let code = challenge.encode();
+        let decoded = Challenge::decode(board(), &code).unwrap();
+        assert_eq!(decoded.found.len(), 73);
+        assert_eq!(decoded.total_milliseconds, None);
+        assert_eq!(decoded.message(Language::English), "Friend: 73. Beat them!");
+    }
+
+    #[test]
+    fn test_challenge_message() {
+        let challenge = Challenge {
+            board: board(),
+            found: (1..=100).collect(),
+            total_milliseconds: Some(754321),
+        };
+        assert_eq!(
+            challenge.message(Language::English),
+            "Friend: 100 in 12:34. Beat them!"
+        );
+        assert_eq!(
+            challenge.message(Language::Spanish),
+            "Amigo: 100 en 12:34. ¡Supéralo!"
+        );
+    }
+
+    #[test]
+    fn test_reject_invalid_challenge() {
+        assert_eq!(Challenge::decode(board(), "!!"), None);
+        assert_eq!(Challenge::decode(board(), "AAAA"), None);
+    }
+}
@@ -8,6 +8,7 @@ use serde::*;
 use yewdux::prelude::*;
 
 use super::info_bar_state::{InfoBarSetMessage, InfoBarState};
+use super::logging::LoggableEvent;
 
 #[derive(PartialEq, Eq, Clone, Default, Serialize, Deserialize)]
 pub struct ChosenPositionsState {
@@ -34,7 +35,7 @@ impl Listener for CPSListener {
     fn on_change(&mut self, state: std::rc::Rc<Self::Store>) {
         let new_chosen_positions = state.positions;
 
-        let board = Dispatch::<FullGameState>::new().get().game.board.clone();
+        let board = shown_board();
 
         let mut letters = new_chosen_positions.iter().map(|c| board[*c]).peekable();
 
@@ -125,9 +126,15 @@ impl Reducer<ChosenPositionsState> for FindNumberMsg {
                     .solve(fs.game.board.clone())
                     .find(|x| x.result == self.number)
                 {
+                    LoggableEvent::CheatUsed {
+                        board: fs.game.board.canonical_string(),
+                        number: self.number,
+                    }
+                    .try_log1();
                     Dispatch::<FullGameState>::new().apply(|mut x: Rc<FullGameState>| {
                         let gs = Rc::make_mut(&mut x);
                         gs.timing = GameTiming::Cheat;
+                        gs.score = gs.calculate_score();
                         x
                     });
 
@@ -182,7 +189,7 @@ impl ChosenPositionsState {
             let mut new_chosen_positions = state.positions;
             new_chosen_positions.push(coordinate);
 
-            let board = Dispatch::<FullGameState>::new().get().game.board.clone();
+            let board = shown_board();
 
             let mut letters = new_chosen_positions.iter().map(|c| board[*c]).peekable();
 
@@ -292,6 +299,30 @@ pub enum ChangeChosenPositionsMessage {
 impl Reducer<ChosenPositionsState> for ChangeChosenPositionsMessage {
     fn apply(self, state: std::rc::Rc<ChosenPositionsState>) -> std::rc::Rc<ChosenPositionsState> {
         //log::debug!("{self:?}");
-        ChosenPositionsState::next(state, self)
+        let dispatch = Dispatch::<FullGameState>::new();
+        let found_words = dispatch.get().found_words.clone();
+        let in_tutorial = Dispatch::<TutorialState>::new().get().is_active();
+
+        //Each path is one turn in multiplayer games
+        if self == ChangeChosenPositionsMessage::Abandon
+            && !state.positions.is_empty()
+            && !in_tutorial
+        {
+            Dispatch::<MultiplayerState>::new().apply(PassTurnMsg);
+        }
+
+        let new_state = ChosenPositionsState::next(state.clone(), self);
+
+        //Finding a word is recorded when it is found
+        if new_state.positions != state.positions
+            && dispatch.get().found_words == found_words
+            && !in_tutorial
+        {
+            dispatch.apply(RecordActionMsg(GameAction::ChoosePath {
+                from: state.positions,
+                to: new_state.positions,
+            }));
+        }
+        new_state
     }
 }
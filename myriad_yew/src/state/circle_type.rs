@@ -6,6 +6,8 @@ use myriad::prelude::*;
 pub enum CircleType {
     Disabled,
     LegalMove,
+    /// A legal move which can lead to a number which has not been found
+    PromisingMove,
     LastPosition,
     IntermediatePosition { next: Tile<GRID_COLUMNS, GRID_ROWS> },
 }
@@ -21,6 +23,15 @@ pub enum CircleType {
 //     }
 // }
 
+impl CircleType {
+    pub fn with_promising(self, promising: bool) -> Self {
+        match self {
+            CircleType::LegalMove if promising => CircleType::PromisingMove,
+            _ => self,
+        }
+    }
+}
+
 impl ChosenPositionsState {
     pub fn get_circle_type(
         &self,
@@ -10,5 +10,8 @@ pub struct DialogState {
 #[derive(PartialEq, Eq, Clone, Copy, Serialize, Deserialize)]
 pub enum CongratsDialogType {
     OneHundred,
+    TimeUp,
+    /// The scores at the end of a multiplayer session
+    Scoreboard,
 }
 
@@ -0,0 +1,370 @@
+use serde::{Deserialize, Serialize};
+use std::cell::Cell;
+use std::collections::VecDeque;
+use std::rc::Rc;
+use std::time::Duration;
+use yewdux::prelude::*;
+
+use super::logging::{is_logging_enabled, EventLog, LogBackend, LoggableEvent};
+use super::user_state::UserState;
+use crate::state::game_timing::now_utc_milliseconds;
+
+/// The most events to keep waiting. The oldest are dropped first.
+pub const MAX_QUEUE_SIZE: usize = 200;
+/// The most events to send in one request
+pub const BATCH_SIZE: usize = 20;
+/// How long to wait for more events before sending a batch
+pub const BATCH_DELAY_MILLISECONDS: i64 = 2_000;
+const BASE_BACKOFF_MILLISECONDS: i64 = 1_000;
+const MAX_BACKOFF_MILLISECONDS: i64 = 5 * 60 * 1_000;
+/// How long a tab may spend sending a batch before another tab can take over
+pub const CLAIM_MILLISECONDS: i64 = 30_000;
+
+thread_local! {
+    static FLUSH_SCHEDULED: Cell<bool> = Cell::new(false);
+    /// Tells this tab apart from others sharing the queue
+    static TAB_ID: u64 = rand::random();
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct QueuedEvent {
+    /// Given when the event happens and kept when it is sent again, so copies can be dropped
+    #[serde(default = "rand::random")]
+    pub id: u64,
+    pub event: LoggableEvent,
+    /// Whether sending this event has failed before
+    #[serde(default)]
+    pub resent: bool,
+}
+
+/// Events waiting to be uploaded
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize, Store)]
+#[store(storage = "local", storage_tab_sync)]
+pub struct EventQueueState {
+    pub events: VecDeque<QueuedEvent>,
+    /// How many uploads in a row have failed
+    pub failures: u32,
+    /// Don't upload again before this time, in utc milliseconds
+    pub retry_after: Option<i64>,
+    /// The tab which is uploading, as the queue is shared by every tab
+    #[serde(default)]
+    pub claim: Option<FlushClaim>,
+}
+
+/// A tab's claim to upload the queue
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FlushClaim {
+    pub tab: u64,
+    /// Other tabs may take over after this time, in utc milliseconds
+    pub until: i64,
+}
+
+/// How long to wait after some number of failed uploads in a row
+pub fn backoff_milliseconds(failures: u32) -> i64 {
+    match failures {
+        0 => 0,
+        n => BASE_BACKOFF_MILLISECONDS
+            .saturating_mul(1 << (n - 1).min(20))
+            .min(MAX_BACKOFF_MILLISECONDS),
+    }
+}
+
+impl QueuedEvent {
+    pub fn new(event: LoggableEvent) -> Self {
+        Self {
+            id: rand::random(),
+            event,
+            resent: false,
+        }
+    }
+}
+
+impl EventQueueState {
+    /// Add an event unless it is already waiting
+    pub fn with_event(&self, queued: QueuedEvent) -> Self {
+        let mut state = self.clone();
+        if state.events.iter().any(|x| x.id == queued.id) {
+            return state;
+        }
+        state.events.push_back(queued);
+        while state.events.len() > MAX_QUEUE_SIZE {
+            state.events.pop_front();
+        }
+        state
+    }
+
+    /// The events to send now, if any
+    pub fn next_batch(&self, now: i64) -> Option<Vec<QueuedEvent>> {
+        if self.events.is_empty() || self.retry_after.is_some_and(|x| now < x) {
+            return None;
+        }
+        Some(self.events.iter().take(BATCH_SIZE).cloned().collect())
+    }
+
+    /// Claim the queue for this tab unless another tab is uploading
+    pub fn with_claim(&self, tab: u64, now: i64) -> Self {
+        let mut state = self.clone();
+        if !state.claim.is_some_and(|x| x.tab != tab && now < x.until) {
+            state.claim = Some(FlushClaim {
+                tab,
+                until: now + CLAIM_MILLISECONDS,
+            });
+        }
+        state
+    }
+
+    pub fn is_claimed_by(&self, tab: u64) -> bool {
+        self.claim.is_some_and(|x| x.tab == tab)
+    }
+
+    /// Remove the sent events and give up the claim.
+    /// They are found by id as the queue may have changed in another tab while they were sent.
+    pub fn after_success(&self, sent: &[u64]) -> Self {
+        let mut events = self.events.clone();
+        events.retain(|x| !sent.contains(&x.id));
+        Self {
+            events,
+            failures: 0,
+            retry_after: None,
+            claim: None,
+        }
+    }
+
+    pub fn after_failure(&self, sent: &[u64], now: i64) -> Self {
+        let mut state = self.clone();
+        for queued in state.events.iter_mut().filter(|x| sent.contains(&x.id)) {
+            queued.resent = true;
+        }
+        state.failures = state.failures.saturating_add(1);
+        state.retry_after = Some(now + backoff_milliseconds(state.failures));
+        state.claim = None;
+        state
+    }
+
+    /// How long to wait before trying to send again
+    pub fn delay_milliseconds(&self, now: i64) -> i64 {
+        let backoff = self.retry_after.map(|x| x - now).unwrap_or_default();
+        backoff.max(BATCH_DELAY_MILLISECONDS)
+    }
+}
+
+/// Queue an event to be uploaded with the next batch
+pub struct QueueEventMsg(pub QueuedEvent);
+
+impl Reducer<EventQueueState> for QueueEventMsg {
+    fn apply(self, state: Rc<EventQueueState>) -> Rc<EventQueueState> {
+        if !is_logging_enabled() {
+            return state;
+        }
+        let state = state.with_event(self.0);
+        schedule_flush(state.delay_milliseconds(now_utc_milliseconds()));
+        state.into()
+    }
+}
+
+/// Upload waiting events after a delay. Only one upload is scheduled at a time.
+pub fn schedule_flush(delay_milliseconds: i64) {
+    if FLUSH_SCHEDULED.with(|x| x.replace(true)) {
+        return;
+    }
+    wasm_bindgen_futures::spawn_local(async move {
+        yew::platform::time::sleep(Duration::from_millis(delay_milliseconds.max(0) as u64)).await;
+        FLUSH_SCHEDULED.with(|x| x.set(false));
+        flush().await;
+    });
+}
+
+async fn flush() {
+    let dispatch = Dispatch::<EventQueueState>::new();
+    if !is_logging_enabled() {
+        dispatch.set(Default::default());
+        return;
+    }
+    let Some(user_id) = Dispatch::<UserState>::new().get().user_id1.clone() else {
+        log::error!("User Id not set");
+        return;
+    };
+
+    let now = now_utc_milliseconds();
+    let tab = TAB_ID.with(|x| *x);
+    if !dispatch.get().events.is_empty() {
+        dispatch.reduce(|s| s.with_claim(tab, now).into());
+    }
+    if !dispatch.get().is_claimed_by(tab) {
+        if !dispatch.get().events.is_empty() {
+            schedule_flush(dispatch.get().delay_milliseconds(now));
+        }
+        return;
+    }
+
+    let Some(batch) = dispatch.get().next_batch(now) else {
+        if !dispatch.get().events.is_empty() {
+            schedule_flush(dispatch.get().delay_milliseconds(now));
+        }
+        return;
+    };
+
+    let logs: Vec<EventLog> = batch
+        .iter()
+        .map(|x| {
+            let mut log = EventLog::new_resent(user_id.clone(), x.id, x.event.clone());
+            log.resent = x.resent;
+            log
+        })
+        .collect();
+
+    let sent: Vec<u64> = batch.iter().map(|x| x.id).collect();
+    match LogBackend::configured().send(&logs).await {
+        Ok(()) => {
+            log::debug!("{} logs sent successfully", sent.len());
+            dispatch.reduce(|s| s.after_success(&sent).into());
+        }
+        Err(err) => {
+            log::error!("Failed to log: {}", err);
+            dispatch.reduce(|s| s.after_failure(&sent, now).into());
+        }
+    }
+
+    let state = dispatch.get();
+    if !state.events.is_empty() {
+        schedule_flush(state.delay_milliseconds(now_utc_milliseconds()));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn event(i: usize) -> LoggableEvent {
+        LoggableEvent::Internal {
+            message: i.to_string(),
+        }
+    }
+
+    fn queued(i: usize) -> QueuedEvent {
+        QueuedEvent::new(event(i))
+    }
+
+    fn ids(batch: &[QueuedEvent]) -> Vec<u64> {
+        batch.iter().map(|x| x.id).collect()
+    }
+
+    #[test]
+    fn test_duplicates_are_dropped() {
+        let first = queued(1);
+        let resent = QueuedEvent {
+            resent: true,
+            ..first.clone()
+        };
+        let state = EventQueueState::default()
+            .with_event(first.clone())
+            .with_event(first)
+            .with_event(resent);
+
+        assert_eq!(state.events.len(), 1);
+    }
+
+    #[test]
+    fn test_repeated_events_are_kept() {
+        let state = EventQueueState::default()
+            .with_event(queued(1))
+            .with_event(queued(1));
+
+        assert_eq!(state.events.len(), 2);
+    }
+
+    #[test]
+    fn test_only_one_tab_uploads() {
+        let now = 1_000_000;
+        let state = EventQueueState::default()
+            .with_event(queued(1))
+            .with_claim(1, now);
+        assert!(state.is_claimed_by(1));
+
+        let other_tab = state.with_claim(2, now + 1);
+        assert!(!other_tab.is_claimed_by(2));
+
+        let expired = state.with_claim(2, now + CLAIM_MILLISECONDS);
+        assert!(expired.is_claimed_by(2));
+
+        let sent = ids(&state.next_batch(now).unwrap());
+        assert_eq!(state.after_success(&sent).claim, None);
+        assert_eq!(state.after_failure(&sent, now).claim, None);
+    }
+
+    #[test]
+    fn test_only_sent_events_are_removed() {
+        let state = EventQueueState::default()
+            .with_event(queued(1))
+            .with_event(queued(2));
+        let batch = state.next_batch(0).unwrap();
+
+        let other_tab = EventQueueState::default()
+            .with_event(queued(3))
+            .with_event(batch[1].clone());
+        let state = other_tab.after_success(&ids(&batch));
+
+        assert_eq!(state.events.len(), 1);
+        assert_eq!(state.events[0].event, event(3));
+    }
+
+    #[test]
+    fn test_queue_is_capped() {
+        let mut state = EventQueueState::default();
+        for i in 0..(MAX_QUEUE_SIZE + 5) {
+            state = state.with_event(queued(i));
+        }
+
+        assert_eq!(state.events.len(), MAX_QUEUE_SIZE);
+        assert_eq!(state.events.front().map(|x| &x.event), Some(&event(5)));
+    }
+
+    #[test]
+    fn test_batches() {
+        let mut state = EventQueueState::default();
+        for i in 0..(BATCH_SIZE + 3) {
+            state = state.with_event(queued(i));
+        }
+
+        let batch = state.next_batch(0).unwrap();
+        assert_eq!(batch.len(), BATCH_SIZE);
+        assert_eq!(batch[0].event, event(0));
+
+        let state = state.after_success(&ids(&batch));
+        let batch = state.next_batch(0).unwrap();
+        assert_eq!(batch.len(), 3);
+        assert_eq!(batch[0].event, event(BATCH_SIZE));
+
+        let state = state.after_success(&ids(&batch));
+        assert_eq!(state.next_batch(0), None);
+    }
+
+    #[test]
+    fn test_failures_back_off() {
+        let state = EventQueueState::default().with_event(queued(1));
+        let sent = ids(&state.next_batch(0).unwrap());
+        let now = 1_000_000;
+
+        let failed = state.after_failure(&sent, now);
+        assert!(failed.events[0].resent);
+        assert_eq!(failed.next_batch(now), None);
+        assert_eq!(failed.next_batch(now + 999), None);
+        assert!(failed.next_batch(now + 1_000).is_some());
+
+        let failed_again = failed.after_failure(&sent, now);
+        assert_eq!(failed_again.retry_after, Some(now + 2_000));
+        assert!(failed_again.delay_milliseconds(now) >= 2_000);
+
+        let recovered = failed_again.after_success(&sent);
+        assert_eq!(recovered.failures, 0);
+        assert!(recovered.events.is_empty());
+    }
+
+    #[test]
+    fn test_backoff_is_capped() {
+        assert_eq!(backoff_milliseconds(0), 0);
+        assert_eq!(backoff_milliseconds(1), 1_000);
+        assert_eq!(backoff_milliseconds(3), 4_000);
+        assert_eq!(backoff_milliseconds(100), MAX_BACKOFF_MILLISECONDS);
+    }
+}
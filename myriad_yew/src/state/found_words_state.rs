@@ -20,6 +20,33 @@ impl FoundWordsTracker {
         }
     }
 
+    pub fn without_word(&self, number: i32) -> Self {
+        let mut new_map = self.words.clone();
+        new_map.remove(&number);
+
+        FoundWordsTracker { words: new_map }
+    }
+
+    /// All words found in either tracker, keeping the shorter path when both found the same number
+    pub fn union(&self, other: &Self) -> Self {
+        let mut new_map = self.words.clone();
+
+        for (number, word) in other.words.iter() {
+            match new_map.entry(*number) {
+                btree_map::Entry::Vacant(entry) => {
+                    entry.insert(word.clone());
+                }
+                btree_map::Entry::Occupied(mut entry) => {
+                    if word.path.len() < entry.get().path.len() {
+                        entry.insert(word.clone());
+                    }
+                }
+            }
+        }
+
+        FoundWordsTracker { words: new_map }
+    }
+
     pub fn has_word(&self, word: &FoundWord<GRID_COLUMNS, GRID_ROWS, GRID_SIZE>) -> bool {
         self.words.contains_key(&word.result)
     }
@@ -1,18 +1,54 @@
 use crate::state::prelude::*;
+use crate::state::versioned_storage::*;
 use myriad::prelude::*;
 use serde::*;
 use serde_with::serde_as;
+use std::num::NonZeroU8;
 use std::rc::Rc;
 use yewdux::prelude::*;
 
-use chrono::{Datelike, NaiveDate};
+use chrono::{Datelike, NaiveDate, NaiveDateTime};
 
-#[derive(PartialEq, Eq, Store, Clone, Serialize, Deserialize)]
-#[store(storage = "local", storage_tab_sync)] // can also be "session"
+#[derive(PartialEq, Eq, Clone, Serialize, Deserialize)]
 pub struct FullGameState {
     pub game: Game,
     pub found_words: Rc<FoundWordsTracker>,
     pub timing: GameTiming,
+    pub hints: Rc<HintsTracker>,
+    pub timed: TimedGame,
+    /// Only the current game can be undone, so games in the history have no actions
+    #[serde(default, skip_serializing_if = "ActionLog::is_empty")]
+    pub actions: ActionLog,
+    pub events: Vec<FoundWordEvent>,
+    /// The day the first number was found, which need not be the date of the board
+    pub played: Option<NaiveDate>,
+    /// Numbers only count when they are found with the shortest possible path
+    pub shortest_paths_only: bool,
+    /// The score when this game was last changed
+    pub score: Score,
+}
+
+impl Store for FullGameState {
+    fn new() -> Self {
+        init_versioned_store()
+    }
+
+    fn should_notify(&self, old: &Self) -> bool {
+        self != old
+    }
+}
+
+impl VersionedStore for FullGameState {
+    const VERSION: u32 = 7;
+    const MIGRATIONS: &'static [Migration] = &[
+        Self::migrate_v0_to_v1,
+        Self::migrate_v1_to_v2,
+        Self::migrate_v2_to_v3,
+        Self::migrate_v3_to_v4,
+        Self::migrate_v4_to_v5,
+        Self::migrate_v5_to_v6,
+        Self::migrate_v6_to_v7,
+    ];
 }
 
 impl Default for FullGameState {
@@ -21,18 +57,253 @@ impl Default for FullGameState {
             game: Game::create_for_today(),
             found_words: Default::default(),
             timing: Default::default(),
+            hints: Default::default(),
+            timed: Default::default(),
+            actions: Default::default(),
+            events: Default::default(),
+            played: None,
+            shortest_paths_only: false,
+            score: Default::default(),
         }
     }
 }
 
 
 impl FullGameState {
+    /// Version 1 added hints
+    pub fn migrate_v0_to_v1(value: &mut serde_json::Value) {
+        if let Some(map) = value.as_object_mut() {
+            map.entry("hints")
+                .or_insert_with(|| serde_json::json!({ "hints": {} }));
+        }
+    }
+
+    /// Version 2 added timed modes
+    pub fn migrate_v1_to_v2(value: &mut serde_json::Value) {
+        if let Some(map) = value.as_object_mut() {
+            map.entry("timed").or_insert_with(|| {
+                serde_json::to_value(TimedGame::default()).expect("Could not serialize timed game")
+            });
+        }
+    }
+
+    /// Version 3 added undo and redo
+    pub fn migrate_v2_to_v3(value: &mut serde_json::Value) {
+        if let Some(map) = value.as_object_mut() {
+            map.entry("actions").or_insert_with(|| {
+                serde_json::to_value(ActionLog::default()).expect("Could not serialize action log")
+            });
+        }
+    }
+
+    /// Version 4 added found word events
+    pub fn migrate_v3_to_v4(value: &mut serde_json::Value) {
+        if let Some(map) = value.as_object_mut() {
+            map.entry("events").or_insert_with(|| serde_json::json!([]));
+        }
+    }
+
+    /// Version 5 added the shortest paths only mode
+    pub fn migrate_v4_to_v5(value: &mut serde_json::Value) {
+        if let Some(map) = value.as_object_mut() {
+            map.entry("shortest_paths_only")
+                .or_insert(serde_json::Value::Bool(false));
+        }
+    }
+
+    /// Version 6 added scores, which are calculated from the words already found
+    pub fn migrate_v5_to_v6(value: &mut serde_json::Value) {
+        let score = Self::score_from_json(value);
+        let Some(map) = value.as_object_mut() else {
+            return;
+        };
+        if map.contains_key("score") {
+            return;
+        }
+        map.insert(
+            "score".to_string(),
+            serde_json::to_value(score).expect("Could not serialize score"),
+        );
+    }
+
+    /// Version 7 added the day each game was played and penalized hints and the time taken in unfinished games.
+    /// Older games use the utc day the first number was found, if that was recorded.
+    pub fn migrate_v6_to_v7(value: &mut serde_json::Value) {
+        let played = Self::event_times_from_json(value)
+            .min()
+            .and_then(NaiveDateTime::from_timestamp_millis)
+            .map(|x| x.date());
+        let score = Self::score_from_json(value);
+
+        if let Some(map) = value.as_object_mut() {
+            map.entry("played").or_insert_with(|| {
+                serde_json::to_value(played).expect("Could not serialize played date")
+            });
+            map.insert(
+                "score".to_string(),
+                serde_json::to_value(score).expect("Could not serialize score"),
+            );
+        }
+    }
+
+    /// The score of a saved game, read from the fields it has had since version 5.
+    /// This must not use the current struct, which may have changed since.
+    fn score_from_json(value: &serde_json::Value) -> Score {
+        let difficulties = value
+            .pointer("/game/difficulties")
+            .and_then(|x| x.as_array());
+        let found = value
+            .pointer("/found_words/words")
+            .and_then(|x| x.as_object())
+            .into_iter()
+            .flatten()
+            .filter_map(|(_, word)| {
+                let index = usize::try_from(word.get("result")?.as_i64()?.checked_sub(1)?).ok()?;
+                let difficulty = u8::try_from(difficulties?.get(index)?.as_u64()?).ok()?;
+                let path_length = word.get("path")?.as_array()?.len();
+                Some((Difficulty(NonZeroU8::new(difficulty)?), path_length))
+            });
+        let hints = value
+            .pointer("/hints/hints")
+            .and_then(|x| x.as_object())
+            .map(|x| x.len())
+            .unwrap_or_default();
+        let timing = value.get("timing");
+        let elapsed_milliseconds =
+            match timing.and_then(|x| x.pointer("/Finished/total_milliseconds")) {
+                Some(total_milliseconds) => total_milliseconds.as_u64(),
+                None => timing
+                    .and_then(|x| x.pointer("/Started/utc_time_milliseconds")?.as_i64())
+                    .and_then(|start| {
+                        let last = Self::event_times_from_json(value).max()?;
+                        u64::try_from(last.saturating_sub(start)).ok()
+                    }),
+            };
+
+        Score::create(
+            found,
+            timing.and_then(|x| x.as_str()) == Some("Cheat"),
+            hints,
+            elapsed_milliseconds,
+        )
+    }
+
+    /// The times numbers were found in a saved game
+    fn event_times_from_json(value: &serde_json::Value) -> impl Iterator<Item = i64> + '_ {
+        value
+            .get("events")
+            .and_then(|x| x.as_array())
+            .into_iter()
+            .flatten()
+            .filter_map(|x| x.get("utc_time_milliseconds")?.as_i64())
+    }
+
+    /// Combine two plays of the same board
+    pub fn merge(&self, other: &Self) -> Self {
+        Self {
+            game: self.game.clone(),
+            found_words: self.found_words.union(&other.found_words).into(),
+            timing: self.timing.best(other.timing),
+            hints: self.hints.union(&other.hints).into(),
+            timed: if self.timed == TimedGame::default() {
+                other.timed.clone()
+            } else {
+                self.timed.clone()
+            },
+            actions: self.actions.clone(),
+            events: if self.events.is_empty() {
+                other.events.clone()
+            } else {
+                self.events.clone()
+            },
+            played: self.played.into_iter().chain(other.played).min(),
+            // Numbers found in a play which allowed any path may not be the shortest
+            shortest_paths_only: self.shortest_paths_only && other.shortest_paths_only,
+            score: Default::default(),
+        }
+        .with_score()
+    }
+
     pub fn get_found_count(&self) -> (usize, usize) {
         let found = self.found_words.words.len();
         let total = self.game.total_solutions;
         (found, total)
     }
 
+    pub fn is_complete(&self) -> bool {
+        let (found, total) = self.get_found_count();
+        found >= total
+    }
+
+    /// The length of the shortest path which makes this number
+    fn shortest_difficulty(&self, number: i32) -> Option<Difficulty> {
+        (number as usize)
+            .checked_sub(1)
+            .and_then(|index| self.game.difficulties.get(index).copied().flatten())
+    }
+
+    /// Whether this path is as short as any which makes its number
+    pub fn is_shortest_path(&self, word: &FoundWord<GRID_COLUMNS, GRID_ROWS, GRID_SIZE>) -> bool {
+        match self.shortest_difficulty(word.result) {
+            Some(shortest) => word.path.len() <= shortest.0.get() as usize,
+            None => true,
+        }
+    }
+
+    pub fn calculate_score(&self) -> Score {
+        let found = self.found_words.words.values().filter_map(|word| {
+            let difficulty = self.shortest_difficulty(word.result)?;
+            Some((difficulty, word.path.len()))
+        });
+        Score::create(
+            found,
+            self.timing == GameTiming::Cheat,
+            self.hints.total_hinted(),
+            self.elapsed_milliseconds(),
+        )
+    }
+
+    /// The time taken to finish the game, or to find the last number in a game which is not finished
+    fn elapsed_milliseconds(&self) -> Option<u64> {
+        match self.timing {
+            GameTiming::Finished { total_milliseconds } => Some(total_milliseconds),
+            GameTiming::Started {
+                utc_time_milliseconds,
+            } => {
+                let last = self.events.iter().map(|x| x.utc_time_milliseconds).max()?;
+                u64::try_from(last.saturating_sub(utc_time_milliseconds)).ok()
+            }
+            GameTiming::Unknown | GameTiming::Cheat => None,
+        }
+    }
+
+    /// This game as it is kept in the history
+    pub fn for_history(&self) -> Self {
+        Self {
+            actions: Default::default(),
+            ..self.clone()
+        }
+    }
+
+    /// Update the stored score after the found words or timing change
+    pub fn with_score(mut self) -> Self {
+        self.score = self.calculate_score();
+        self
+    }
+
+    /// Whether every number found so far used the shortest possible path
+    pub fn is_all_optimal(&self) -> bool {
+        self.found_words
+            .words
+            .values()
+            .all(|word| self.is_shortest_path(word))
+    }
+
+    /// Every number was found with the shortest possible path and without cheating
+    pub fn is_perfect(&self) -> bool {
+        self.is_complete() && self.timing != GameTiming::Cheat && self.is_all_optimal()
+    }
+
     pub fn is_tab_complete(&self, index: i32) -> bool {
         let range = ((index * GOALSIZE) + 1)..(((index + 1) * GOALSIZE) + 1);
 
@@ -114,20 +385,23 @@ impl Game {
 
     pub fn from_string(s: &str) -> Option<Self> {
         let board = Board::<3, 3, 9>::try_create_canonical(s)?;
+
+        Some(Self::from_board(board, None))
+    }
+
+    pub fn from_board(board: Board<3, 3, 9>, date: Option<NaiveDate>) -> Self {
         let solve_settings = SolveSettings::default();
 
         let difficulties = Self::get_difficulties(solve_settings, &board);
         let total_solutions = difficulties.iter().filter(|x| x.is_some()).count();
 
-        let game = Self {
+        Self {
             board,
-            date: None,
+            date,
             solve_settings,
             total_solutions,
             difficulties: difficulties.into(),
-        };
-
-        Some(game)
+        }
     }
 
     pub fn create_random() -> Self {
@@ -192,3 +466,210 @@ impl Game {
 //         Game::create_for_today()
 //     }
 // }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use myriad::prelude::HintLevel;
+
+    #[test]
+    fn test_load_full_game_state_v0() {
+        let state =
+            FullGameState::from_json(include_str!("../../fixtures/full_game_state_v0.json"))
+                .unwrap();
+
+        assert_eq!(state.game.board.to_single_string(), "132+645-9");
+        assert_eq!(state.game.date, None);
+        assert_eq!(
+            state.found_words.words.keys().copied().collect::<Vec<_>>(),
+            vec![1, 2, 3, 10, 42, 100]
+        );
+        assert!(
+            state.timing
+                == GameTiming::Started {
+                    utc_time_milliseconds: 1686000000000
+                }
+        );
+        assert_eq!(state.hints.total_hinted(), 0);
+        assert_eq!(state.timed, TimedGame::default());
+    }
+
+    #[test]
+    fn test_load_full_game_state_v1() {
+        let state =
+            FullGameState::from_json(include_str!("../../fixtures/full_game_state_v1.json"))
+                .unwrap();
+
+        assert_eq!(state.found_words.words.len(), 6);
+        assert_eq!(state.hints.hints.get(&7), Some(&HintLevel::PathLength));
+        assert_eq!(state.hints.hints.get(&99), Some(&HintLevel::StartTile));
+        assert_eq!(state.timed, TimedGame::default());
+    }
+
+    #[test]
+    fn test_load_full_game_state_v2() {
+        let state =
+            FullGameState::from_json(include_str!("../../fixtures/full_game_state_v2.json"))
+                .unwrap();
+
+        assert_eq!(state.found_words.words.len(), 6);
+        assert_eq!(state.hints.total_hinted(), 2);
+        assert_eq!(state.timed, TimedGame::new(TimedMode::Countdown));
+        assert_eq!(state.actions, ActionLog::default());
+        assert!(state.events.is_empty());
+    }
+
+    #[test]
+    fn test_load_full_game_state_v3() {
+        let state =
+            FullGameState::from_json(include_str!("../../fixtures/full_game_state_v3.json"))
+                .unwrap();
+
+        assert_eq!(state.found_words.words.len(), 6);
+        assert_eq!(state.actions.undo.len(), 3);
+        assert!(matches!(
+            state.actions.redo.as_slice(),
+            [GameAction::FindWord {
+                previous: Some(_),
+                ..
+            }]
+        ));
+    }
+
+    #[test]
+    fn test_load_full_game_state_v4() {
+        let state =
+            FullGameState::from_json(include_str!("../../fixtures/full_game_state_v4.json"))
+                .unwrap();
+
+        assert_eq!(state.actions.undo.len(), 3);
+        assert_eq!(state.events.len(), 4);
+        assert!(state
+            .events
+            .iter()
+            .all(|x| state.found_words.words.get(&x.word.result) == Some(&x.word)));
+    }
+
+    #[test]
+    fn test_load_full_game_state_v5() {
+        let v4 = FullGameState::from_json(include_str!("../../fixtures/full_game_state_v4.json"))
+            .unwrap();
+        let v5 = FullGameState::from_json(include_str!("../../fixtures/full_game_state_v5.json"))
+            .unwrap();
+
+        assert!(!v4.shortest_paths_only);
+        assert!(v5.shortest_paths_only);
+        assert!(v4.found_words == v5.found_words);
+    }
+
+    #[test]
+    fn test_perfect_game() {
+        let history =
+            HistoryState::from_json(include_str!("../../fixtures/history_state_v1.json")).unwrap();
+        let mut game = history.games[0].clone();
+        assert!(game.is_complete());
+
+        let shortest = game
+            .found_words
+            .words
+            .keys()
+            .filter_map(|number| game.game.board.find_shortest(*number, &[]))
+            .fold(FoundWordsTracker::default(), |acc, word| {
+                acc.with_word(word)
+            });
+        game.found_words = shortest.into();
+        assert!(game.is_all_optimal());
+        assert!(game.is_perfect());
+
+        game.timing = GameTiming::Cheat;
+        assert!(!game.is_perfect());
+    }
+
+    #[test]
+    fn test_load_full_game_state_v6() {
+        let v5 = FullGameState::from_json(include_str!("../../fixtures/full_game_state_v5.json"))
+            .unwrap();
+        let v6 = FullGameState::from_json(include_str!("../../fixtures/full_game_state_v6.json"))
+            .unwrap();
+
+        assert_eq!(v5.score, v5.calculate_score());
+        assert_eq!(v5.score, v6.score);
+        assert!(v6.score.total() > 0);
+    }
+
+    #[test]
+    fn test_load_full_game_state_v7() {
+        let v6 = FullGameState::from_json(include_str!("../../fixtures/full_game_state_v6.json"))
+            .unwrap();
+        let v7 = FullGameState::from_json(include_str!("../../fixtures/full_game_state_v7.json"))
+            .unwrap();
+
+        assert!(v6 == v7);
+        assert_eq!(v7.played, NaiveDate::from_ymd_opt(2023, 6, 5));
+        assert_eq!(v7.score, v7.calculate_score());
+        assert!(v7.score.penalty > 0);
+    }
+
+    #[test]
+    fn test_score() {
+        let history =
+            HistoryState::from_json(include_str!("../../fixtures/history_state_v1.json")).unwrap();
+        let mut game = history.games[0].clone();
+        let score = game.calculate_score();
+
+        game.timing = GameTiming::Cheat;
+        assert!(game.calculate_score().total() < score.total());
+
+        game.found_words = Default::default();
+        assert_eq!(game.calculate_score().total(), 0);
+    }
+
+    #[test]
+    fn test_unfinished_games_are_penalized_for_time() {
+        let history =
+            HistoryState::from_json(include_str!("../../fixtures/history_state_v7.json")).unwrap();
+        let finished = history.games[0].clone();
+        let GameTiming::Finished { total_milliseconds } = finished.timing else {
+            panic!("Expected a finished game");
+        };
+        let last_found = finished
+            .events
+            .iter()
+            .map(|x| x.utc_time_milliseconds)
+            .max()
+            .unwrap();
+
+        let mut unfinished = finished.clone();
+        unfinished.timing = GameTiming::Started {
+            utc_time_milliseconds: last_found - total_milliseconds as i64,
+        };
+        assert!(finished.score.penalty > 0);
+        assert_eq!(unfinished.calculate_score(), finished.score);
+
+        let mut hinted = finished.clone();
+        hinted.hints = hinted.hints.with_hint(1, HintLevel::NextTile).into();
+        assert!(hinted.calculate_score().total() < finished.score.total());
+    }
+
+    #[test]
+    fn test_merge_shortest_paths_only() {
+        let history =
+            HistoryState::from_json(include_str!("../../fixtures/history_state_v1.json")).unwrap();
+        let mut strict = history.games[0].clone();
+        strict.shortest_paths_only = true;
+        let mut lenient = strict.clone();
+        lenient.shortest_paths_only = false;
+
+        assert!(strict.merge(&strict).shortest_paths_only);
+        assert!(!strict.merge(&lenient).shortest_paths_only);
+        assert!(!lenient.merge(&strict).shortest_paths_only);
+        assert!(!lenient.merge(&lenient).shortest_paths_only);
+    }
+
+    #[test]
+    fn test_reject_newer_version() {
+        let json = r#"{"version":9999,"data":{}}"#;
+
+        assert!(FullGameState::from_json(json).is_err());
+    }
+}
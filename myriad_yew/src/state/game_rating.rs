@@ -28,6 +28,14 @@ impl SuboptimalWord {
 }
 
 impl GameRating {
+    /// How close the found paths are to the shortest possible, as a percentage
+    pub fn efficiency_percent(&self) -> u32 {
+        if self.actual_steps == 0 {
+            return 100;
+        }
+        (self.min_steps * 100) / self.actual_steps
+    }
+
     pub fn create(state: &FullGameState) -> Self {
         let mut map: BTreeMap<i32, FoundWord<GRID_COLUMNS, GRID_ROWS, GRID_SIZE>> =
             Default::default();
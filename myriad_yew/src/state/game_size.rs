@@ -11,6 +11,8 @@ pub struct GameSize {
     // pub width: f32,
     // pub height: f32,
     pub orientation: Orientation,
+    /// Put the panel to the left of the board in landscape
+    pub left_handed: bool,
 }
 
 #[derive(Copy, Clone, PartialEq, Debug, Eq, Default)]
@@ -35,7 +37,8 @@ impl Reducer<GameSize> for SetSizeMessage {
         }
 
         let s = std::rc::Rc::make_mut(&mut state);
-        *s = GameSize::from_width_and_height(self.width as f32, self.height as f32);
+        *s = GameSize::from_width_and_height(self.width, self.height)
+            .with_left_handed(s.left_handed);
 
         state
     }
@@ -48,6 +51,7 @@ impl Default for GameSize {
             outer_length: 650.,
 
             orientation: Orientation::default(),
+            left_handed: false,
         }
     }
 }
@@ -64,28 +68,51 @@ impl CenterStyle for Location {
 
 impl GameSize {
     pub fn from_width_and_height(width: f32, height: f32) -> Self {
-        let orientation = if width <= height || height > GameSize::default().outer_length {
-            Orientation::Vertical
-        } else {
+        let vertical_board_length = width.min(height * 8. / 13.0).min(400.);
+
+        //Leave room for the panel even if it is wider than the board
+        let horizontal_board_length = height
+            .min(((width - SIDE_PANEL_MARGIN) / 2.).max(width - SIDE_PANEL_MARGIN - FOUND_WORDS_WIDTH))
+            .min(400.);
+
+        //Tablets in landscape can fit a full size board either way, so prefer side by side
+        let orientation = if width > height && horizontal_board_length >= vertical_board_length {
             Orientation::Horizontal
+        } else {
+            Orientation::Vertical
         };
 
         let board_length = match orientation {
-            Orientation::Vertical => width.min(height * 8. / 13.0),
-            Orientation::Horizontal => height.min(width * 8. / 13.),
-        }
-        .min(400.);
+            Orientation::Vertical => vertical_board_length,
+            Orientation::Horizontal => horizontal_board_length,
+        };
 
         let outer_length = match orientation{
             Orientation::Vertical=> width.min(board_length * 1.5),
-            Orientation::Horizontal => height.min(board_length * 1.2)
+            Orientation::Horizontal => board_length + SIDE_PANEL_MARGIN + board_length.max(FOUND_WORDS_WIDTH)
         };
 
 
         Self {
             orientation,
             board_length,
-            outer_length
+            outer_length,
+            left_handed: false,
+        }
+    }
+
+    pub fn with_left_handed(self, left_handed: bool) -> Self {
+        Self {
+            left_handed,
+            ..self
+        }
+    }
+
+    /// How far the board is from the left of the container
+    pub fn board_origin_x(&self) -> f32 {
+        match self.orientation {
+            Orientation::Horizontal if self.left_handed => self.panel_width() + SIDE_PANEL_MARGIN,
+            _ => 0.0,
         }
     }
 
@@ -107,14 +134,59 @@ impl GameSize {
         self.board_length / 8.0
     }
 
+    /// The width of the panel holding the info bar, tab headers and found words
+    fn panel_width(&self) -> f32 {
+        match self.orientation {
+            Orientation::Vertical => self.board_length,
+            Orientation::Horizontal => self.board_length.max(FOUND_WORDS_WIDTH),
+        }
+    }
+
+    /// The top left of the panel. It is below the board in portrait and to the right of it in landscape.
+    fn panel_origin(&self) -> (f32, f32) {
+        match self.orientation {
+            Orientation::Vertical => (0.0, self.board_length),
+            Orientation::Horizontal => (
+                if self.left_handed {
+                    0.0
+                } else {
+                    self.board_length + SIDE_PANEL_MARGIN
+                },
+                (self.board_length - self.panel_height()) / 2.0,
+            ),
+        }
+    }
+
+    fn panel_height(&self) -> f32 {
+        INFO_BAR_HEIGHT
+            + TAB_HEADER_TOP_MARGIN
+            + self.tab_header_diameter()
+            + FOUND_WORD_TOP_PADDING
+            + FOUND_WORD_MARGIN
+            + (FOUND_WORD_HEIGHT + FOUND_WORD_MARGIN) * 2.0
+    }
+
     pub fn outer_container_style(&self) -> String {
 
-        let board_other_length = self.board_length * 13./ 8.;
-        let board_length = self.outer_length;
+        let outer_length = self.outer_length;
+        let board_length = self.board_length;
 
         match self.orientation {
-            Orientation::Vertical => format!("width: {board_length}px;") ,
-            Orientation::Horizontal => format!("height: {board_length}px; width: {board_other_length}px;"),
+            Orientation::Vertical => format!("width: {outer_length}px;") ,
+            Orientation::Horizontal => format!("height: {board_length}px; width: {outer_length}px;"),
+        }
+    }
+
+    /// Where to put controls on pages without found words, such as replays and the tutorial
+    pub fn controls_style(&self) -> String {
+        match self.orientation {
+            Orientation::Vertical => "".to_string(),
+            Orientation::Horizontal => {
+                let (x, y) = self.panel_origin();
+                let width = self.panel_width();
+                let top = y + INFO_BAR_HEIGHT;
+                format!("left: {x}px; width: {width}px; top: {top}px; bottom: auto;")
+            }
         }
     }
 
@@ -127,43 +199,22 @@ impl GameSize {
 
         let board_other_length = self.board_length * 13./ 8.;
         let board_length = self.board_length;
+        let outer_length = self.outer_length;
 
         match self.orientation {
             Orientation::Vertical => format!("width: {board_length}px; height: {board_other_length}px; --circle-diameter: {circle_diameter}px; --circle-radius: {circle_radius}px; --tab-header-diameter: {tab_header_diameter}px; --tab-header-font-size: {tab_header_font_size}px;"),
-            Orientation::Horizontal => format!("height: {board_length}px; width: {board_other_length}px; --circle-diameter: {circle_diameter}px; --circle-radius: {circle_radius}px; --tab-header-diameter: {tab_header_diameter}px; --tab-header-font-size: {tab_header_font_size}px;"),
+            Orientation::Horizontal => format!("height: {board_length}px; width: {outer_length}px; --circle-diameter: {circle_diameter}px; --circle-radius: {circle_radius}px; --tab-header-diameter: {tab_header_diameter}px; --tab-header-font-size: {tab_header_font_size}px;"),
         }
     }
 
     pub fn get_info_bar_position(&self) -> (f32, f32) {
-        match self.orientation {
-            Orientation::Vertical => {
-                let x = 0.0;
-                let y = self.board_length;
-
-                (x, y)
-            }
-            Orientation::Horizontal => {
-                let x = self.board_length;
-                let y = 0.0;
-                (x, y)
-            }
-        }
+        let (x, y) = self.panel_origin();
+        let x = x + (self.panel_width() - self.board_length) / 2.0;
+        (x, y)
     }
-    pub fn get_info_bar_size(&self) -> (f32, f32) {
-        let width: f32;
-        let height: f32;
-        match self.orientation {
-            Orientation::Vertical => {
-                width = self.board_length;
-                height = INFO_BAR_HEIGHT;
-            }
-            Orientation::Horizontal => {
-                width = INFO_BAR_HEIGHT;
-                height = self.board_length;
-            }
-        }
 
-        (width, height)
+    pub fn get_info_bar_size(&self) -> (f32, f32) {
+        (self.board_length, INFO_BAR_HEIGHT)
     }
 
     pub fn get_found_word_position(
@@ -177,86 +228,48 @@ impl GameSize {
         let row_number = ((number - 1) % GOALSIZE) / 10;
         let row_position = ((number - 1) % GOALSIZE) % 10;
 
+        let (panel_x, panel_y) = self.panel_origin();
+
+        let tab_y = panel_y
+            + FOUND_WORD_TOP_PADDING
+            + self.tab_header_diameter()
+            + TAB_HEADER_TOP_MARGIN
+            + FOUND_WORD_MARGIN
+            + INFO_BAR_HEIGHT
+            + (FOUND_WORD_HEIGHT + FOUND_WORD_MARGIN) * row_number as f32;
+
+        let found_word_padding = (self.panel_width() - FOUND_WORDS_WIDTH) / 2.0;
+
+        let tab_x = panel_x
+            + found_word_padding
+            + row_position as f32 * (FOUND_WORD_MARGIN + FOUND_WORD_WIDTH);
+
+        let index = (number - 1) / GOALSIZE;
+        let mut index_offset = (index - selected_index as i32) as f32;
+        if clamp {
+            index_offset = index_offset.min(1.0).max(-1.0);
+        }
+
         match self.orientation {
-            Orientation::Vertical => {
-                let y = self.board_length
-                    + FOUND_WORD_TOP_PADDING
-                    + self.tab_header_diameter()
-                    + TAB_HEADER_TOP_MARGIN
-                    + FOUND_WORD_MARGIN
-                    + INFO_BAR_HEIGHT
-                    + (FOUND_WORD_HEIGHT + FOUND_WORD_MARGIN) * row_number as f32;
-
-                let found_word_padding =
-                    (self.board_length - (FOUND_WORD_WIDTH * 10.0 + FOUND_WORD_MARGIN * 9.0)) / 2.0;
-
-                let tab_x = found_word_padding
-                    + row_position as f32 * (FOUND_WORD_MARGIN + FOUND_WORD_WIDTH);
-
-                let index = (number - 1) / GOALSIZE;
-                let mut index_offset = (index - selected_index as i32) as f32;
-                if clamp {
-                    index_offset = index_offset.min(1.0).max(-1.0);
-                }
-
-                let offset_x = index_offset * self.board_length;
-
-                let x = tab_x + offset_x;
-                (x, y)
-            }
-            Orientation::Horizontal => {
-                let x = self.board_length
-                    + FOUND_WORD_TOP_PADDING
-                    + self.tab_header_diameter()
-                    + TAB_HEADER_TOP_MARGIN
-                    + FOUND_WORD_MARGIN
-                    + INFO_BAR_HEIGHT
-                    + (FOUND_WORD_HEIGHT + FOUND_WORD_MARGIN) * row_number as f32;
-
-                let found_word_padding =
-                    (self.board_length - (FOUND_WORD_WIDTH * 10.0 + FOUND_WORD_MARGIN * 9.0)) / 2.0;
-
-                let tab_y = found_word_padding
-                    + row_position as f32 * (FOUND_WORD_MARGIN + FOUND_WORD_HEIGHT);
-
-                let index = (number - 1) / GOALSIZE;
-                let mut index_offset = (index - selected_index as i32) as f32;
-                if clamp {
-                    index_offset = index_offset.min(1.0).max(-1.0);
-                }
-
-                let offset_y = index_offset * self.board_length;
-
-                let y = tab_y + offset_y;
-                (x, y)
-            }
+            //Other tabs wait off to the sides
+            Orientation::Vertical => (tab_x + index_offset * self.board_length, tab_y),
+            //Other tabs wait above and below so they never cover the board
+            Orientation::Horizontal => (tab_x, tab_y + index_offset * self.board_length),
         }
     }
 
     pub fn get_tab_header_position(&self, index: usize) -> (f32, f32) {
-        match self.orientation {
-            Orientation::Vertical => {
-                let tab_header_padding = (self.board_length
-                    - ((self.tab_header_diameter() + 3.0) * 6.0 + TAB_HEADER_MARGIN * 5.0))
-                    / 2.0;
-
-                let x = tab_header_padding
-                    + (index as f32 * (self.tab_header_diameter() + TAB_HEADER_MARGIN));
-                let y = (self.square_length() * 3.0) + TAB_HEADER_TOP_MARGIN + INFO_BAR_HEIGHT;
-                (x, y)
-            }
-            Orientation::Horizontal => {
-                let tab_header_padding = (self.board_length
-                    - ((self.tab_header_diameter() + 3.0) * 6.0 + TAB_HEADER_MARGIN * 5.0))
-                    / 2.0;
+        let (panel_x, panel_y) = self.panel_origin();
 
-                let x = self.board_length + INFO_BAR_HEIGHT + TAB_HEADER_TOP_MARGIN;
-                let y = tab_header_padding
-                    + (index as f32 * (self.tab_header_diameter() + TAB_HEADER_MARGIN));
+        let tab_header_padding = (self.panel_width()
+            - ((self.tab_header_diameter() + 3.0) * 6.0 + TAB_HEADER_MARGIN * 5.0))
+            / 2.0;
 
-                (x, y)
-            }
-        }
+        let x = panel_x
+            + tab_header_padding
+            + (index as f32 * (self.tab_header_diameter() + TAB_HEADER_MARGIN));
+        let y = panel_y + TAB_HEADER_TOP_MARGIN + INFO_BAR_HEIGHT;
+        (x, y)
     }
 }
 
@@ -271,3 +284,65 @@ pub const FOUND_WORD_WIDTH: f32 = 30.0;
 pub const FOUND_WORD_HEIGHT: f32 = 30.0;
 pub const FOUND_WORD_MARGIN: f32 = 5.0;
 pub const FOUND_WORD_TOP_PADDING: f32 = 10.0;
+/// The width of a row of ten found words
+pub const FOUND_WORDS_WIDTH: f32 = FOUND_WORD_WIDTH * 10.0 + FOUND_WORD_MARGIN * 9.0;
+/// The gap between the board and the panel in landscape
+pub const SIDE_PANEL_MARGIN: f32 = 20.0;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_orientation() {
+        //Phone in portrait
+        assert_eq!(GameSize::from_width_and_height(390., 844.).orientation, Orientation::Vertical);
+        //Phone in landscape
+        assert_eq!(GameSize::from_width_and_height(844., 390.).orientation, Orientation::Horizontal);
+        //Tablet in landscape
+        let tablet = GameSize::from_width_and_height(1024., 768.);
+        assert_eq!(tablet.orientation, Orientation::Horizontal);
+        assert_eq!(tablet.board_length, 400.);
+        //Tablet in portrait
+        assert_eq!(GameSize::from_width_and_height(768., 1024.).orientation, Orientation::Vertical);
+    }
+
+    #[test]
+    fn test_landscape_panel_is_beside_the_board() {
+        for (width, height) in [(844., 390.), (1024., 768.), (700., 320.)] {
+            let size = GameSize::from_width_and_height(width, height);
+            assert_eq!(size.orientation, Orientation::Horizontal);
+            assert!(size.outer_length <= width, "{width}x{height} is too wide");
+
+            //The buttons are on the first tab and 1 to 20 are on the second
+            for (number, tab) in (101..=120).map(|x| (x, 0)).chain((1..=20).map(|x| (x, 1))) {
+                let (x, y) = size.get_found_word_position(number, tab, false);
+                assert!(x >= size.board_length, "{number} overlaps the board");
+                assert!(x + FOUND_WORD_WIDTH <= size.outer_length);
+                assert!(y >= 0. && y + FOUND_WORD_HEIGHT <= size.board_length);
+            }
+
+            //Other tabs are hidden below the container
+            let (_, y) = size.get_found_word_position(21, 1, false);
+            assert!(y >= size.board_length);
+        }
+    }
+
+    #[test]
+    fn test_left_handed_panel_is_left_of_the_board() {
+        let size = GameSize::from_width_and_height(844., 390.).with_left_handed(true);
+        for number in 101..=120 {
+            let (x, _) = size.get_found_word_position(number, 0, false);
+            assert!(x + FOUND_WORD_WIDTH <= size.board_origin_x(), "{number} overlaps the board");
+        }
+        assert!(size.board_origin_x() + size.board_length <= size.outer_length);
+
+        //Portrait is the same either way
+        let portrait = GameSize::from_width_and_height(390., 844.);
+        assert_eq!(portrait.with_left_handed(true).board_origin_x(), 0.0);
+        assert_eq!(
+            portrait.with_left_handed(true).get_found_word_position(7, 1, false),
+            portrait.get_found_word_position(7, 1, false)
+        );
+    }
+}
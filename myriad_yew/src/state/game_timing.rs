@@ -1,5 +1,5 @@
 use serde::*;
-#[derive(PartialEq, Eq, Clone, Copy, Serialize, Deserialize)]
+#[derive(PartialEq, Eq, Clone, Copy, Serialize, Deserialize, Debug)]
 pub enum GameTiming {
     Started { utc_time_milliseconds: i64 },
     Finished { total_milliseconds: u64 },
@@ -10,11 +10,90 @@ pub enum GameTiming {
 
 impl Default for GameTiming {
     fn default() -> Self {
-        let js_today = js_sys::Date::new_0();
-        let utc_time = js_today.get_time();
-        let utc_time_milliseconds = utc_time.floor() as i64;
+        Self::started_now()
+    }
+}
+
+/// The current UTC time in milliseconds
+pub fn now_utc_milliseconds() -> i64 {
+    let js_today = js_sys::Date::new_0();
+    let utc_time = js_today.get_time();
+    utc_time.floor() as i64
+}
+
+impl GameTiming {
+    pub fn started_now() -> Self {
         Self::Started {
-            utc_time_milliseconds,
+            utc_time_milliseconds: now_utc_milliseconds(),
+        }
+    }
+
+    /// Milliseconds since the game started, if it is still being played
+    pub fn elapsed_milliseconds(&self, now_utc_milliseconds: i64) -> Option<u64> {
+        match self {
+            GameTiming::Started {
+                utc_time_milliseconds,
+            } => Some(
+                now_utc_milliseconds
+                    .saturating_sub(*utc_time_milliseconds)
+                    .max(0) as u64,
+            ),
+            _ => None,
+        }
+    }
+
+    /// The finishing time as minutes and seconds.
+    /// Times of one hundred minutes or more are not shown.
+    pub fn time_string(&self) -> Option<String> {
+        let GameTiming::Finished { total_milliseconds } = self else {
+            return None;
+        };
+        let total_seconds = total_milliseconds / 1000;
+        let minutes = total_seconds / 60;
+        if minutes >= 100 {
+            return None;
         }
+        let seconds = total_seconds % 60;
+        Some(format!("{minutes:02}:{seconds:02}"))
+    }
+
+    /// The better of two timings for the same game.
+    /// A finished game beats an unfinished one and a faster finish beats a slower one.
+    /// Cheating beats an unfinished game, so it is only replaced by a finish on another device.
+    pub fn best(self, other: Self) -> Self {
+        fn rank(timing: &GameTiming) -> (u8, i64) {
+            match timing {
+                GameTiming::Finished { total_milliseconds } => (3, -(*total_milliseconds as i64)),
+                GameTiming::Cheat => (2, 0),
+                GameTiming::Started { .. } => (1, 0),
+                GameTiming::Unknown => (0, 0),
+            }
+        }
+
+        if rank(&other) > rank(&self) {
+            other
+        } else {
+            self
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_best_keeps_cheating() {
+        let started = GameTiming::Started {
+            utc_time_milliseconds: 0,
+        };
+        let finished = GameTiming::Finished {
+            total_milliseconds: 1000,
+        };
+
+        assert!(started.best(GameTiming::Cheat) == GameTiming::Cheat);
+        assert!(GameTiming::Cheat.best(started) == GameTiming::Cheat);
+        assert!(GameTiming::Unknown.best(GameTiming::Cheat) == GameTiming::Cheat);
+        assert!(GameTiming::Cheat.best(finished) == finished);
     }
 }
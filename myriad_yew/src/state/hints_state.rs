@@ -0,0 +1,127 @@
+use crate::state::info_bar_state::InfoBarState;
+use crate::state::logging::LoggableEvent;
+use crate::state::prelude::*;
+use myriad::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::collections::*;
+use std::rc::Rc;
+use yewdux::prelude::*;
+
+/// The most revealing hint used for each number in a game
+#[derive(PartialEq, Eq, Clone, Serialize, Deserialize, Default)]
+pub struct HintsTracker {
+    pub hints: BTreeMap<i32, HintLevel>,
+}
+
+impl HintsTracker {
+    pub fn with_hint(&self, number: i32, level: HintLevel) -> Self {
+        let mut new_map = self.hints.clone();
+
+        let entry = new_map.entry(number).or_insert(level);
+        *entry = level.max(*entry);
+
+        HintsTracker { hints: new_map }
+    }
+
+    /// The level of the next hint to give for this number
+    pub fn next_level(&self, number: i32) -> HintLevel {
+        self.hints
+            .get(&number)
+            .map(|x| x.next())
+            .unwrap_or(HintLevel::StartTile)
+    }
+
+    /// The most revealing hint used for each number in either tracker
+    pub fn union(&self, other: &Self) -> Self {
+        other
+            .hints
+            .iter()
+            .fold(self.clone(), |acc, (number, level)| {
+                acc.with_hint(*number, *level)
+            })
+    }
+
+    /// The number of numbers for which hints were used
+    pub fn total_hinted(&self) -> usize {
+        self.hints.len()
+    }
+}
+
+pub fn hint_text(
+    hint: &Hint<GRID_COLUMNS, GRID_ROWS>,
+    board: &Board<GRID_COLUMNS, GRID_ROWS, GRID_SIZE>,
+    language: Language,
+) -> String {
+    match hint {
+        Hint::StartTile(tile) => format!("{}…", board[*tile]),
+        Hint::Operator(Some(operator)) => format!("…{operator}…"),
+        Hint::Operator(None) => language.translate(Text::HintNoOperator).to_string(),
+        Hint::PathLength(length) => language.translate_with(Text::HintTiles, &[length]),
+        Hint::NextTile(tile) => format!("→{}", board[*tile]),
+    }
+}
+
+/// Clicking a number which has not been found shows its difficulty.
+/// Clicking it again gives successively stronger hints.
+pub struct UnfoundNumberClickedMsg {
+    pub number: i32,
+    pub difficulty: Difficulty,
+}
+
+impl Reducer<InfoBarState> for UnfoundNumberClickedMsg {
+    fn apply(self, state: Rc<InfoBarState>) -> Rc<InfoBarState> {
+        let number = self.number;
+        if state.number() != Some(number) {
+            return InfoBarState::Difficulty {
+                number,
+                difficulty: self.difficulty,
+            }
+            .into();
+        }
+
+        let fs = Dispatch::<FullGameState>::new().get();
+        let prefix = Dispatch::<ChosenPositionsState>::new().get().positions;
+        let board = &fs.game.board;
+        let level = fs.hints.next_level(number);
+
+        let hint = board.get_hint(number, level, &prefix).or_else(|| {
+            //The current path cannot be continued to make this number so suggest starting again
+            board.get_hint(number, HintLevel::StartTile, &[])
+        });
+
+        let Some(hint) = hint else {
+            return state;
+        };
+        //Record the hint actually given, which may be a start tile instead of the level asked for
+        let level = hint.level();
+
+        LoggableEvent::HintUsed {
+            board: board.canonical_string(),
+            number,
+            level,
+        }
+        .try_log1();
+        Dispatch::<FullGameState>::new().apply(HintUsedMsg { number, level });
+
+        InfoBarState::Hint {
+            number,
+            hint,
+            text: hint_text(&hint, board, current_language()),
+        }
+        .into()
+    }
+}
+
+pub struct HintUsedMsg {
+    pub number: i32,
+    pub level: HintLevel,
+}
+
+impl Reducer<FullGameState> for HintUsedMsg {
+    fn apply(self, mut state: Rc<FullGameState>) -> Rc<FullGameState> {
+        let gs = Rc::make_mut(&mut state);
+        gs.hints = gs.hints.with_hint(self.number, self.level).into();
+        gs.score = gs.calculate_score();
+        state
+    }
+}
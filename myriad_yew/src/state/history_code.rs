@@ -0,0 +1,238 @@
+use crate::state::prelude::*;
+use base64::Engine;
+use chrono::{Datelike, NaiveDate};
+use myriad::prelude::*;
+use std::collections::BTreeMap;
+use std::rc::Rc;
+
+/// Identifies history codes so that other text, and codes in an older format, can be rejected
+pub const HISTORY_CODE_PREFIX: &str = "MYRIAD2:";
+
+/// The number of bytes needed to store which numbers from 1 to 100 have been found
+pub const BITSET_BYTES: usize = 13;
+/// The path length of each number from 1 to 100, one nibble per number
+const PATH_LENGTH_BYTES: usize = 50;
+const GAME_BYTES: usize = GRID_SIZE + 4 + PATH_LENGTH_BYTES + 5;
+
+const TIMING_UNKNOWN: u8 = 0;
+const TIMING_FINISHED: u8 = 1;
+const TIMING_CHEAT: u8 = 2;
+
+/// Store which numbers from 1 to 100 have been found, one bit per number
+pub fn encode_found_numbers<'a>(numbers: impl Iterator<Item = &'a i32>) -> [u8; BITSET_BYTES] {
+    let mut bitset = [0u8; BITSET_BYTES];
+    for number in numbers {
+        if (1..=100).contains(number) {
+            let index = (*number - 1) as usize;
+            bitset[index / 8] |= 1 << (index % 8);
+        }
+    }
+    bitset
+}
+
+pub fn decode_found_numbers(bitset: &[u8]) -> impl Iterator<Item = i32> + '_ {
+    (0..100usize)
+        .filter(|index| {
+            bitset
+                .get(index / 8)
+                .is_some_and(|byte| byte & (1 << (index % 8)) != 0)
+        })
+        .map(|index| index as i32 + 1)
+}
+
+/// Store the path length of each found number, with zero for numbers which have not been found
+fn encode_path_lengths<'a>(
+    words: impl Iterator<Item = &'a FoundWord<GRID_COLUMNS, GRID_ROWS, GRID_SIZE>>,
+) -> [u8; PATH_LENGTH_BYTES] {
+    let mut lengths = [0u8; PATH_LENGTH_BYTES];
+    for word in words {
+        if (1..=100).contains(&word.result) {
+            let index = (word.result - 1) as usize;
+            lengths[index / 2] |= (word.path.len() as u8 & 0x0F) << ((index % 2) * 4);
+        }
+    }
+    lengths
+}
+
+fn decode_path_lengths(lengths: &[u8]) -> impl Iterator<Item = (i32, usize)> + '_ {
+    (0..100usize)
+        .map(|index| {
+            let length = lengths
+                .get(index / 2)
+                .map(|byte| (byte >> ((index % 2) * 4)) & 0x0F)
+                .unwrap_or_default();
+            (index as i32 + 1, length as usize)
+        })
+        .filter(|(_, length)| *length > 0)
+}
+
+/// Encode the history as a short text code which can be copied between devices.
+/// Only the board, date, path length of each found number and finished time of each game are kept.
+pub fn encode_history_code(history: &HistoryState) -> String {
+    let mut bytes: Vec<u8> = Vec::with_capacity(history.games.len() * GAME_BYTES);
+
+    for state in history.games.iter() {
+        bytes.extend(state.game.board.0.iter().map(|rune| *rune as u8));
+
+        let days = state
+            .game
+            .date
+            .map(|date| date.num_days_from_ce() as u32)
+            .unwrap_or_default();
+        bytes.extend(days.to_le_bytes());
+
+        bytes.extend(encode_path_lengths(state.found_words.words.values()));
+
+        let (tag, milliseconds) = match state.timing {
+            GameTiming::Finished { total_milliseconds } => (
+                TIMING_FINISHED,
+                total_milliseconds.min(u32::MAX as u64) as u32,
+            ),
+            GameTiming::Cheat => (TIMING_CHEAT, 0),
+            GameTiming::Started { .. } | GameTiming::Unknown => (TIMING_UNKNOWN, 0),
+        };
+        bytes.push(tag);
+        bytes.extend(milliseconds.to_le_bytes());
+    }
+
+    let encoded = base64::engine::general_purpose::URL_SAFE_NO_PAD.encode(bytes);
+    format!("{HISTORY_CODE_PREFIX}{encoded}")
+}
+
+/// Decode a history code.
+/// Found words are given a path of the same length as the original, so whether it was optimal is kept.
+pub fn decode_history_code(code: &str) -> Result<HistoryState, anyhow::Error> {
+    let encoded = code
+        .trim()
+        .strip_prefix(HISTORY_CODE_PREFIX)
+        .ok_or_else(|| anyhow::anyhow!("Not a Myriad history code"))?;
+
+    let bytes = base64::engine::general_purpose::URL_SAFE_NO_PAD.decode(encoded)?;
+
+    if bytes.len() % GAME_BYTES != 0 {
+        anyhow::bail!("History code has the wrong length");
+    }
+
+    let games = bytes
+        .chunks_exact(GAME_BYTES)
+        .map(decode_game)
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(HistoryState { games })
+}
+
+fn decode_game(bytes: &[u8]) -> Result<FullGameState, anyhow::Error> {
+    let (board_bytes, rest) = bytes.split_at(GRID_SIZE);
+    let (date_bytes, rest) = rest.split_at(4);
+    let (path_lengths, rest) = rest.split_at(PATH_LENGTH_BYTES);
+    let (timing_bytes, _) = rest.split_at(5);
+
+    let runes: [Rune; GRID_SIZE] = board_bytes
+        .iter()
+        .map(|b| Rune::from_repr(*b as usize).ok_or_else(|| anyhow::anyhow!("Invalid rune {b}")))
+        .collect::<Result<Vec<_>, _>>()?
+        .try_into()
+        .map_err(|_| anyhow::anyhow!("Invalid board"))?;
+    let board = Board::<GRID_COLUMNS, GRID_ROWS, GRID_SIZE>(TileMap::from_inner(runes));
+
+    let days = u32::from_le_bytes(date_bytes.try_into()?);
+    let date = match days {
+        0 => None,
+        days => Some(
+            NaiveDate::from_num_days_from_ce_opt(days as i32)
+                .ok_or_else(|| anyhow::anyhow!("Invalid date"))?,
+        ),
+    };
+
+    let milliseconds = u32::from_le_bytes(timing_bytes[1..].try_into()?);
+    let timing = match timing_bytes[0] {
+        TIMING_FINISHED => GameTiming::Finished {
+            total_milliseconds: milliseconds as u64,
+        },
+        TIMING_CHEAT => GameTiming::Cheat,
+        _ => GameTiming::Unknown,
+    };
+
+    let game = Game::from_board(board, date);
+
+    // Paths are only searched until every found number has one, as solving the whole board is slow
+    let mut lengths: BTreeMap<i32, usize> = decode_path_lengths(path_lengths).collect();
+    let mut words = BTreeMap::new();
+    if !lengths.is_empty() {
+        for word in game.solve_settings.solve_all_paths(game.board.clone()) {
+            if lengths.get(&word.result) == Some(&word.path.len()) {
+                lengths.remove(&word.result);
+                words.insert(word.result, word);
+                if lengths.is_empty() {
+                    break;
+                }
+            }
+        }
+    }
+    if let Some((number, length)) = lengths.into_iter().next() {
+        anyhow::bail!("{number} cannot be made with {length} tiles on this board");
+    }
+    let found_words = FoundWordsTracker { words };
+
+    Ok(FullGameState {
+        game,
+        found_words: Rc::new(found_words),
+        timing,
+        hints: Default::default(),
+        timed: Default::default(),
+        actions: Default::default(),
+        events: Default::default(),
+        played: None,
+        shortest_paths_only: false,
+        score: Default::default(),
+    }
+    .with_score())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::versioned_storage::VersionedStore;
+
+    #[test]
+    fn test_history_code_round_trip() {
+        let history =
+            HistoryState::from_json(include_str!("../../fixtures/history_state_v1.json")).unwrap();
+
+        let code = encode_history_code(&history);
+        assert!(code.starts_with(HISTORY_CODE_PREFIX));
+
+        let decoded = decode_history_code(&code).unwrap();
+        assert_eq!(decoded.games.len(), history.games.len());
+
+        for (original, decoded) in history.games.iter().zip(decoded.games.iter()) {
+            assert!(original.game == decoded.game);
+            assert!(original.timing == decoded.timing);
+            assert_eq!(
+                original.found_words.words.keys().collect::<Vec<_>>(),
+                decoded.found_words.words.keys().collect::<Vec<_>>()
+            );
+            for (number, word) in decoded.found_words.words.iter() {
+                assert_eq!(decoded.game.board.check(&word.path), Ok(*number));
+                assert_eq!(
+                    word.path.len(),
+                    original.found_words.words[number].path.len()
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_reject_invalid_codes() {
+        assert!(decode_history_code("hello").is_err());
+        assert!(decode_history_code(&format!("{HISTORY_CODE_PREFIX}AAAA")).is_err());
+        assert!(decode_history_code(&format!("{HISTORY_CODE_PREFIX}!!!")).is_err());
+    }
+
+    #[test]
+    fn test_empty_history_code() {
+        let code = encode_history_code(&HistoryState::default());
+        assert_eq!(code, HISTORY_CODE_PREFIX);
+        assert!(decode_history_code(&code).unwrap().games.is_empty());
+    }
+}
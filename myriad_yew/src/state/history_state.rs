@@ -1,15 +1,55 @@
 use crate::state::prelude::*;
+use crate::state::versioned_storage::*;
 use itertools::Itertools;
 use serde::*;
 use std::rc::Rc;
 use yewdux::prelude::*;
 
-#[derive(PartialEq, Eq, Store, Clone, Default, Serialize, Deserialize)]
-#[store(storage = "local", storage_tab_sync)] // can also be "session"
+#[derive(PartialEq, Eq, Clone, Default, Serialize, Deserialize)]
 pub struct HistoryState {
     pub games: Vec<FullGameState>,
 }
 
+impl Store for HistoryState {
+    fn new() -> Self {
+        init_versioned_store()
+    }
+
+    fn should_notify(&self, old: &Self) -> bool {
+        self != old
+    }
+}
+
+impl VersionedStore for HistoryState {
+    const VERSION: u32 = 7;
+    const MIGRATIONS: &'static [Migration] = &[
+        |value| Self::migrate_games(value, FullGameState::migrate_v0_to_v1),
+        |value| Self::migrate_games(value, FullGameState::migrate_v1_to_v2),
+        |value| Self::migrate_games(value, FullGameState::migrate_v2_to_v3),
+        |value| Self::migrate_games(value, FullGameState::migrate_v3_to_v4),
+        |value| Self::migrate_games(value, FullGameState::migrate_v4_to_v5),
+        |value| Self::migrate_games(value, FullGameState::migrate_v5_to_v6),
+        |value| Self::migrate_games(value, Self::migrate_game_v6_to_v7),
+    ];
+}
+
+impl HistoryState {
+    /// Apply a game migration to every game in the history
+    fn migrate_games(value: &mut serde_json::Value, migration: Migration) {
+        if let Some(games) = value.get_mut("games").and_then(|x| x.as_array_mut()) {
+            games.iter_mut().for_each(migration);
+        }
+    }
+
+    /// Version 7 stopped keeping the actions which can be undone for games in the history
+    fn migrate_game_v6_to_v7(value: &mut serde_json::Value) {
+        if let Some(map) = value.as_object_mut() {
+            map.remove("actions");
+        }
+        FullGameState::migrate_v6_to_v7(value);
+    }
+}
+
 
 impl HistoryState{
     pub fn all_games_including_current<'a, 'b>  (&'a self, current: &'b FullGameState)-> impl Iterator<Item = &'a FullGameState> where 'b : 'a{
@@ -18,6 +58,47 @@ impl HistoryState{
     }
 }
 
+impl HistoryState {
+    /// Combine two histories.
+    /// Games for the same board are merged and games only in `other` are added at the end.
+    pub fn merge(&self, other: &Self) -> Self {
+        let mut games = self.games.clone();
+
+        for game in other.games.iter() {
+            if let Some(existing) = games.iter_mut().find(|x| x.game.board == game.game.board) {
+                *existing = existing.merge(game);
+            } else {
+                games.push(game.clone());
+            }
+        }
+
+        Self { games }
+    }
+}
+
+/// Merge imported games into the history and into the current game
+pub struct ImportHistoryMsg(pub HistoryState);
+
+impl Reducer<HistoryState> for ImportHistoryMsg {
+    fn apply(self, state: Rc<HistoryState>) -> Rc<HistoryState> {
+        let current = Dispatch::<FullGameState>::new().get();
+        if let Some(imported) = self
+            .0
+            .games
+            .iter()
+            .find(|x| x.game.board == current.game.board)
+        {
+            Dispatch::<FullGameState>::new().set(current.merge(imported));
+        }
+
+        let new_state = state.merge(&self.0);
+        if new_state == *state {
+            return state;
+        }
+        new_state.into()
+    }
+}
+
 pub struct SaveGameMessage(pub Rc<FullGameState>);
 
 impl Reducer<HistoryState> for SaveGameMessage {
@@ -33,10 +114,200 @@ impl Reducer<HistoryState> for SaveGameMessage {
             .find_position(|x| x.game.board == self.0.game.board)
         {
             new_state.games[index].found_words = self.0.found_words.clone();
+            new_state.games[index].hints = self.0.hints.clone();
+            new_state.games[index].timed = self.0.timed.clone();
+            new_state.games[index].events = self.0.events.clone();
+            new_state.games[index].score = new_state.games[index].calculate_score();
         } else {
-            new_state.games.push(self.0.as_ref().clone());
+            new_state.games.push(self.0.for_history());
         }
 
         Rc::new(new_state)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use myriad::prelude::HintLevel;
+
+    #[test]
+    fn test_load_history_v0() {
+        let history =
+            HistoryState::from_json(include_str!("../../fixtures/history_state_v0.json")).unwrap();
+
+        assert_eq!(history.games.len(), 2);
+
+        let first = &history.games[0];
+        assert_eq!(first.game.date, chrono::NaiveDate::from_ymd_opt(2023, 6, 1));
+        assert_eq!(first.get_found_count(), (100, 100));
+        assert!(
+            first.timing
+                == GameTiming::Finished {
+                    total_milliseconds: 754321
+                }
+        );
+        assert_eq!(first.hints.total_hinted(), 0);
+
+        let second = &history.games[1];
+        assert_eq!(second.game.board.to_single_string(), "ⅠⅠⅤ-Ⅹ+ⅬⅭⅩ");
+        assert!(second.timing == GameTiming::Cheat);
+        assert_eq!(
+            second.found_words.words.keys().copied().collect::<Vec<_>>(),
+            vec![5, 7]
+        );
+    }
+
+    #[test]
+    fn test_load_history_v1() {
+        let history =
+            HistoryState::from_json(include_str!("../../fixtures/history_state_v1.json")).unwrap();
+
+        assert_eq!(history.games.len(), 2);
+        assert_eq!(history.games[0].hints.total_hinted(), 0);
+        assert_eq!(
+            history.games[1].hints.hints.get(&12),
+            Some(&HintLevel::NextTile)
+        );
+    }
+
+    #[test]
+    fn test_load_history_v2() {
+        let history =
+            HistoryState::from_json(include_str!("../../fixtures/history_state_v2.json")).unwrap();
+
+        let timed = &history.games[0].timed;
+        assert_eq!(timed.mode, TimedMode::TimeAttack);
+        assert_eq!(timed.splits.len(), 10);
+        assert_eq!(
+            timed.splits.last(),
+            Some(&Split {
+                found: 100,
+                milliseconds: 754321
+            })
+        );
+        assert_eq!(history.games[1].timed, TimedGame::default());
+    }
+
+    #[test]
+    fn test_migrated_history_matches_current() {
+        let v0 =
+            HistoryState::from_json(include_str!("../../fixtures/history_state_v0.json")).unwrap();
+        let v1 =
+            HistoryState::from_json(include_str!("../../fixtures/history_state_v1.json")).unwrap();
+        let v2 =
+            HistoryState::from_json(include_str!("../../fixtures/history_state_v2.json")).unwrap();
+
+        assert!(v0.games[0] == v1.games[0]);
+        assert!(v0.games[1].found_words == v1.games[1].found_words);
+        assert!(v0.games[1].game == v1.games[1].game);
+
+        assert!(v1.games[1] == v2.games[1]);
+        assert!(v1.games[0].found_words == v2.games[0].found_words);
+        assert!(v1.games[0].timing == v2.games[0].timing);
+
+        let v3 =
+            HistoryState::from_json(include_str!("../../fixtures/history_state_v3.json")).unwrap();
+
+        assert!(v2.games[1] == v3.games[1]);
+        assert!(v2.games[0].timed == v3.games[0].timed);
+        assert_eq!(v3.games[0].actions, ActionLog::default());
+
+        let v4 =
+            HistoryState::from_json(include_str!("../../fixtures/history_state_v4.json")).unwrap();
+
+        assert!(v3.games[1] == v4.games[1]);
+        assert!(v3.games[0].events.is_empty());
+        assert_eq!(v4.games[0].events.len(), 2);
+
+        let v5 =
+            HistoryState::from_json(include_str!("../../fixtures/history_state_v5.json")).unwrap();
+
+        assert!(v4.games[1] == v5.games[1]);
+        assert!(v4.games[0].events == v5.games[0].events);
+        assert!(!v4.games[0].shortest_paths_only);
+        assert!(v5.games[0].shortest_paths_only);
+
+        let v6 =
+            HistoryState::from_json(include_str!("../../fixtures/history_state_v6.json")).unwrap();
+
+        assert!(v5.games == v6.games);
+        assert!(v6.games.iter().all(|x| x.score == x.calculate_score()));
+
+        let v7 =
+            HistoryState::from_json(include_str!("../../fixtures/history_state_v7.json")).unwrap();
+
+        assert!(v6.games == v7.games);
+        assert!(!include_str!("../../fixtures/history_state_v7.json").contains("actions"));
+        assert!(v7.games[0].played.is_some());
+        assert_eq!(v7.games[1].played, None);
+        assert!(v7.games.iter().all(|x| x.score == x.calculate_score()));
+    }
+
+    #[test]
+    fn test_merge_history() {
+        let history =
+            HistoryState::from_json(include_str!("../../fixtures/history_state_v1.json")).unwrap();
+
+        let mut other = history.clone();
+        let cheat_game = &mut other.games[1];
+        let extra_word = cheat_game.game.board.find_shortest(12, &[]).unwrap();
+        cheat_game.found_words = cheat_game.found_words.with_word(extra_word).into();
+        cheat_game.timing = GameTiming::Finished {
+            total_milliseconds: 1000,
+        };
+        other.games[0].timing = GameTiming::Finished {
+            total_milliseconds: 999_999,
+        };
+        other.games.remove(0);
+        let new_game =
+            FullGameState::from_json(include_str!("../../fixtures/full_game_state_v1.json"))
+                .unwrap();
+        other.games.push(new_game.clone());
+
+        let merged = history.merge(&other);
+
+        assert_eq!(merged.games.len(), 3);
+        assert!(merged.games[0] == history.games[0]);
+        assert_eq!(
+            merged.games[1]
+                .found_words
+                .words
+                .keys()
+                .copied()
+                .collect::<Vec<_>>(),
+            vec![5, 7, 12]
+        );
+        assert!(
+            merged.games[1].timing
+                == GameTiming::Finished {
+                    total_milliseconds: 1000
+                }
+        );
+        assert!(merged.games[2] == new_game);
+    }
+
+    #[test]
+    fn test_merge_keeps_best_timing() {
+        let history =
+            HistoryState::from_json(include_str!("../../fixtures/history_state_v1.json")).unwrap();
+
+        let mut slower = history.clone();
+        slower.games[0].timing = GameTiming::Finished {
+            total_milliseconds: 999_999,
+        };
+        slower.games[0].found_words = Default::default();
+
+        assert!(history.merge(&slower) == history);
+        assert!(slower.merge(&history) == history);
+    }
+
+    #[test]
+    fn test_history_round_trip() {
+        let history =
+            HistoryState::from_json(include_str!("../../fixtures/history_state_v7.json")).unwrap();
+        let json = history.to_json().unwrap();
+
+        assert_eq!(json, include_str!("../../fixtures/history_state_v7.json"));
+    }
+}
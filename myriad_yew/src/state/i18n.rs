@@ -0,0 +1,821 @@
+use crate::state::preferences_state::PreferencesState;
+use serde::{Deserialize, Serialize};
+use strum::EnumIter;
+use yewdux::prelude::*;
+
+/// A language the interface has been translated into
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize, EnumIter)]
+pub enum Language {
+    #[default]
+    English,
+    Spanish,
+    French,
+    German,
+}
+
+impl Language {
+    pub fn code(&self) -> &'static str {
+        match self {
+            Language::English => "en",
+            Language::Spanish => "es",
+            Language::French => "fr",
+            Language::German => "de",
+        }
+    }
+
+    /// The name of the language in that language
+    pub fn native_name(&self) -> &'static str {
+        match self {
+            Language::English => "English",
+            Language::Spanish => "Español",
+            Language::French => "Français",
+            Language::German => "Deutsch",
+        }
+    }
+
+    /// The language for a tag such as "en-GB", ignoring the region
+    pub fn from_tag(tag: &str) -> Option<Self> {
+        let code = tag.split(['-', '_']).next()?.to_ascii_lowercase();
+        <Self as strum::IntoEnumIterator>::iter().find(|x| x.code() == code)
+    }
+
+    /// The language the device is set to, if it has been translated.
+    /// This is only looked up once.
+    pub fn device() -> Option<Self> {
+        thread_local! {
+            static DEVICE_LANGUAGE: Option<Language> = web_sys::window()
+                .and_then(|w| w.navigator().language())
+                .and_then(|tag| Language::from_tag(tag.as_str()));
+        }
+        DEVICE_LANGUAGE.with(|x| *x)
+    }
+
+    fn group_separator(&self) -> &'static str {
+        match self {
+            Language::English => ",",
+            Language::Spanish | Language::German => ".",
+            Language::French => "\u{202F}",
+        }
+    }
+
+    /// Format a whole number with this language's digit grouping
+    pub fn format_integer(&self, number: i64) -> String {
+        let digits = number.unsigned_abs().to_string();
+        let mut result = String::new();
+        for (i, digit) in digits.chars().enumerate() {
+            if i > 0 && (digits.len() - i) % 3 == 0 {
+                result.push_str(self.group_separator());
+            }
+            result.push(digit);
+        }
+        if number < 0 {
+            result.insert(0, '-');
+        }
+        result
+    }
+
+    pub fn format_percent(&self, percent: i64) -> String {
+        let number = self.format_integer(percent);
+        match self {
+            Language::English | Language::Spanish => format!("{number}%"),
+            Language::French | Language::German => format!("{number}\u{00A0}%"),
+        }
+    }
+
+    pub fn month_names(&self) -> [&'static str; 12] {
+        match self {
+            Language::English => [
+                "January",
+                "February",
+                "March",
+                "April",
+                "May",
+                "June",
+                "July",
+                "August",
+                "September",
+                "October",
+                "November",
+                "December",
+            ],
+            Language::Spanish => [
+                "Enero",
+                "Febrero",
+                "Marzo",
+                "Abril",
+                "Mayo",
+                "Junio",
+                "Julio",
+                "Agosto",
+                "Septiembre",
+                "Octubre",
+                "Noviembre",
+                "Diciembre",
+            ],
+            Language::French => [
+                "Janvier",
+                "Février",
+                "Mars",
+                "Avril",
+                "Mai",
+                "Juin",
+                "Juillet",
+                "Août",
+                "Septembre",
+                "Octobre",
+                "Novembre",
+                "Décembre",
+            ],
+            Language::German => [
+                "Januar",
+                "Februar",
+                "März",
+                "April",
+                "Mai",
+                "Juni",
+                "Juli",
+                "August",
+                "September",
+                "Oktober",
+                "November",
+                "Dezember",
+            ],
+        }
+    }
+
+    /// Short weekday names, starting on Monday
+    pub fn weekday_names(&self) -> [&'static str; 7] {
+        match self {
+            Language::English => ["Mo", "Tu", "We", "Th", "Fr", "Sa", "Su"],
+            Language::Spanish => ["Lu", "Ma", "Mi", "Ju", "Vi", "Sá", "Do"],
+            Language::French => ["Lu", "Ma", "Me", "Je", "Ve", "Sa", "Di"],
+            Language::German => ["Mo", "Di", "Mi", "Do", "Fr", "Sa", "So"],
+        }
+    }
+
+    pub fn translate(&self, text: Text) -> &'static str {
+        match self {
+            Language::English => english(text),
+            Language::Spanish => spanish(text),
+            Language::French => french(text),
+            Language::German => german(text),
+        }
+    }
+
+    /// Translate text and fill in its placeholders. `{0}` is replaced by the first argument and so on.
+    pub fn translate_with(&self, text: Text, args: &[&dyn std::fmt::Display]) -> String {
+        let mut result = self.translate(text).to_string();
+        for (i, arg) in args.iter().enumerate() {
+            result = result.replace(format!("{{{i}}}").as_str(), arg.to_string().as_str());
+        }
+        result
+    }
+}
+
+/// The language to use outside of components
+pub fn current_language() -> Language {
+    Dispatch::<PreferencesState>::new().get().current_language()
+}
+
+/// A piece of interface text
+#[derive(Debug, Clone, Copy, PartialEq, Eq, EnumIter)]
+pub enum Text {
+    Ok,
+    Share,
+    ShareDialogTitle,
+    NewGame,
+    Today,
+    Random,
+    History,
+    Stats,
+    Statistics,
+    Calendar,
+    Tutorial,
+    Export,
+    Import,
+    CopyCode,
+    PasteCode,
+    ImportedGames,
+    CodeCopied,
+    HistoryExported,
+
+    Settings,
+    Theme,
+    ThemeAuto,
+    ThemeLight,
+    ThemeDark,
+    Language,
+    HighContrast,
+    Haptics,
+    Confetti,
+    DifficultyDots,
+    ShowTimer,
+    LeftHanded,
+    Analytics,
+    ShortestPathsOnly,
+    PerfectGame,
+    On,
+    Off,
+
+    WellDone100,
+    TimeUp,
+    PlayerWins,
+    Draw,
+    YouCheated,
+    HintUsed,
+    HintsUsed,
+    PerfectScore,
+    Score,
+    SuboptimalSolution,
+    SuboptimalSolutions,
+    HardSolution,
+    HardSolutions,
+
+    GamesPlayed,
+    Completed,
+    CurrentStreak,
+    LongestStreak,
+    AverageTime,
+    BestTime,
+    FoundByDifficulty,
+    MostMissed,
+    CalendarStreaks,
+
+    Achievements,
+    AchievementUnlocked,
+    Locked,
+    AchievementHonest,
+    AchievementHonestDescription,
+    AchievementOptimal,
+    AchievementOptimalDescription,
+    AchievementWeekStreak,
+    AchievementWeekStreakDescription,
+    AchievementSpeedy,
+    AchievementSpeedyDescription,
+
+    Replay,
+    NothingToReplay,
+
+    WellDone,
+    Next,
+    Play,
+    Skip,
+    TutorialFirstNumber,
+    TutorialDigitsJoin,
+    TutorialAdjacent,
+    TutorialLeftToRight,
+    TutorialOnYourOwn,
+
+    FoundNumber,
+    AlreadyFound,
+    OutOfRange,
+    NotShortest,
+    HasDifficulty,
+    JoinedRace,
+    FoundFirst,
+    RaceUnavailable,
+
+    BoardLabel,
+    TileLabel,
+    SpokenPlus,
+    SpokenTimes,
+    SpokenMinus,
+    SpokenDivide,
+    SpokenBlank,
+    SpokenRoman,
+
+    HintNoOperator,
+    HintTiles,
+    ChallengeTimed,
+    ChallengeUntimed,
+    PlayerShort,
+}
+
+fn english(text: Text) -> &'static str {
+    use Text::*;
+    match text {
+        Ok => "Ok",
+        Share => "Share",
+        ShareDialogTitle => "Myriad Share",
+        NewGame => "New Game",
+        Today => "TODAY",
+        Random => "RANDOM",
+        History => "History",
+        Stats => "Stats",
+        Statistics => "Statistics",
+        Calendar => "Calendar",
+        Tutorial => "Tutorial",
+        Export => "Export",
+        Import => "Import",
+        CopyCode => "Copy Code",
+        PasteCode => "Paste Code",
+        ImportedGames => "Imported {0} games",
+        CodeCopied => "Code copied",
+        HistoryExported => "History exported",
+
+        Settings => "Settings",
+        Theme => "Theme",
+        ThemeAuto => "Auto",
+        ThemeLight => "Light",
+        ThemeDark => "Dark",
+        Language => "Language",
+        HighContrast => "High Contrast",
+        Haptics => "Vibration",
+        Confetti => "Confetti",
+        DifficultyDots => "Difficulty Dots",
+        ShowTimer => "Timer",
+        LeftHanded => "Left Handed",
+        Analytics => "Usage Data",
+        ShortestPathsOnly => "Shortest Paths Only",
+        PerfectGame => "Perfect",
+        On => "On",
+        Off => "Off",
+
+        WellDone100 => "Well done, you got 💯!",
+        TimeUp => "Time's up! You found {0}",
+        PlayerWins => "Player {0} wins!",
+        Draw => "It's a draw!",
+        YouCheated => "You Cheated!",
+        HintUsed => "1 Hint Used",
+        HintsUsed => "{0} Hints Used",
+        PerfectScore => "Perfect Score",
+        Score => "Score",
+        SuboptimalSolution => "{0} Suboptimal Solution",
+        SuboptimalSolutions => "{0} Suboptimal Solutions",
+        HardSolution => "{0} Hard Solution",
+        HardSolutions => "{0} Hard Solutions",
+
+        GamesPlayed => "Games Played",
+        Completed => "Completed",
+        CurrentStreak => "Current Streak",
+        LongestStreak => "Longest Streak",
+        AverageTime => "Average Time",
+        BestTime => "Best Time",
+        FoundByDifficulty => "Found by Difficulty",
+        MostMissed => "Most Missed",
+        CalendarStreaks => "Streak {0} · Longest {1}",
+
+        Achievements => "Achievements",
+        AchievementUnlocked => "Achievement Unlocked",
+        Locked => "Locked",
+        AchievementHonest => "Honest",
+        AchievementHonestDescription => "Find every number without cheating",
+        AchievementOptimal => "Shortcut",
+        AchievementOptimalDescription => "Find every number by the shortest path",
+        AchievementWeekStreak => "Week Streak",
+        AchievementWeekStreakDescription => "Play the daily game 7 days in a row",
+        AchievementSpeedy => "Speedy",
+        AchievementSpeedyDescription => "Find every number in under 10 minutes",
+
+        Replay => "Replay",
+        NothingToReplay => "Nothing to replay",
+
+        WellDone => "Well done!",
+        Next => "Next",
+        Play => "Play",
+        Skip => "Skip",
+        TutorialFirstNumber => "Drag 3 → + → 4 to make 7",
+        TutorialDigitsJoin => "Digits next to each other join up. Drag 1 → 2 → + → 3 to make 15",
+        TutorialAdjacent => "Each tile must touch the last one, diagonals count. Make 7",
+        TutorialLeftToRight => {
+            "Sums are worked out left to right. Drag 2 → + → 3 → × → 4 to make 20"
+        }
+        TutorialOnYourOwn => "Now on your own. Make 9",
+
+        FoundNumber => "Found {0}",
+        AlreadyFound => "{0} already found",
+        OutOfRange => "{0} is out of range",
+        NotShortest => "{0} has a shorter path",
+        HasDifficulty => "{0} has difficulty {1}",
+        JoinedRace => "{0} joined the race",
+        FoundFirst => "{0} found {1} first",
+        RaceUnavailable => "Racing is not available",
+
+        BoardLabel => "Board",
+        TileLabel => "{0}, row {1} column {2}",
+        SpokenPlus => "plus",
+        SpokenTimes => "times",
+        SpokenMinus => "minus",
+        SpokenDivide => "divided by",
+        SpokenBlank => "blank",
+        SpokenRoman => "Roman {0}",
+
+        HintNoOperator => "No Operator",
+        HintTiles => "{0} Tiles",
+        ChallengeTimed => "Friend: {0} in {1}. Beat them!",
+        ChallengeUntimed => "Friend: {0}. Beat them!",
+        PlayerShort => "P{0}",
+    }
+}
+
+fn spanish(text: Text) -> &'static str {
+    use Text::*;
+    match text {
+        Ok => "Vale",
+        Share => "Compartir",
+        ShareDialogTitle => "Compartir Myriad",
+        NewGame => "Nueva partida",
+        Today => "HOY",
+        Random => "AZAR",
+        History => "Historial",
+        Stats => "Estadísticas",
+        Statistics => "Estadísticas",
+        Calendar => "Calendario",
+        Tutorial => "Tutorial",
+        Export => "Exportar",
+        Import => "Importar",
+        CopyCode => "Copiar código",
+        PasteCode => "Pegar código",
+        ImportedGames => "{0} partidas importadas",
+        CodeCopied => "Código copiado",
+        HistoryExported => "Historial exportado",
+
+        Settings => "Ajustes",
+        Theme => "Tema",
+        ThemeAuto => "Automático",
+        ThemeLight => "Claro",
+        ThemeDark => "Oscuro",
+        Language => "Idioma",
+        HighContrast => "Alto contraste",
+        Haptics => "Vibración",
+        Confetti => "Confeti",
+        DifficultyDots => "Puntos de dificultad",
+        ShowTimer => "Cronómetro",
+        LeftHanded => "Zurdo",
+        Analytics => "Datos de uso",
+        ShortestPathsOnly => "Solo caminos más cortos",
+        PerfectGame => "Perfecto",
+        On => "Sí",
+        Off => "No",
+
+        WellDone100 => "¡Bien hecho, conseguiste 💯!",
+        TimeUp => "¡Se acabó el tiempo! Encontraste {0}",
+        PlayerWins => "¡Gana el jugador {0}!",
+        Draw => "¡Es un empate!",
+        YouCheated => "¡Hiciste trampa!",
+        HintUsed => "1 pista usada",
+        HintsUsed => "{0} pistas usadas",
+        PerfectScore => "Puntuación perfecta",
+        Score => "Puntuación",
+        SuboptimalSolution => "{0} solución mejorable",
+        SuboptimalSolutions => "{0} soluciones mejorables",
+        HardSolution => "{0} solución difícil",
+        HardSolutions => "{0} soluciones difíciles",
+
+        GamesPlayed => "Partidas jugadas",
+        Completed => "Completadas",
+        CurrentStreak => "Racha actual",
+        LongestStreak => "Mejor racha",
+        AverageTime => "Tiempo medio",
+        BestTime => "Mejor tiempo",
+        FoundByDifficulty => "Encontrados por dificultad",
+        MostMissed => "Más olvidados",
+        CalendarStreaks => "Racha {0} · Récord {1}",
+
+        Achievements => "Logros",
+        AchievementUnlocked => "¡Logro desbloqueado!",
+        Locked => "Bloqueado",
+        AchievementHonest => "Honesto",
+        AchievementHonestDescription => "Encuentra todos los números sin hacer trampa",
+        AchievementOptimal => "Atajo",
+        AchievementOptimalDescription => "Encuentra todos los números por el camino más corto",
+        AchievementWeekStreak => "Racha semanal",
+        AchievementWeekStreakDescription => "Juega el juego diario 7 días seguidos",
+        AchievementSpeedy => "Veloz",
+        AchievementSpeedyDescription => "Encuentra todos los números en menos de 10 minutos",
+
+        Replay => "Repetición",
+        NothingToReplay => "Nada que repetir",
+
+        WellDone => "¡Bien hecho!",
+        Next => "Siguiente",
+        Play => "Jugar",
+        Skip => "Saltar",
+        TutorialFirstNumber => "Arrastra 3 → + → 4 para hacer 7",
+        TutorialDigitsJoin => "Los dígitos contiguos se unen. Arrastra 1 → 2 → + → 3 para hacer 15",
+        TutorialAdjacent => "Cada casilla debe tocar la anterior, también en diagonal. Haz 7",
+        TutorialLeftToRight => {
+            "Las cuentas van de izquierda a derecha. Arrastra 2 → + → 3 → × → 4 para hacer 20"
+        }
+        TutorialOnYourOwn => "Ahora te toca a ti. Haz 9",
+
+        FoundNumber => "Encontrado {0}",
+        AlreadyFound => "{0} ya encontrado",
+        OutOfRange => "{0} está fuera de rango",
+        NotShortest => "{0} tiene un camino más corto",
+        HasDifficulty => "{0} tiene dificultad {1}",
+        JoinedRace => "{0} se unió a la carrera",
+        FoundFirst => "{0} encontró {1} primero",
+        RaceUnavailable => "Las carreras no están disponibles",
+
+        BoardLabel => "Tablero",
+        TileLabel => "{0}, fila {1} columna {2}",
+        SpokenPlus => "más",
+        SpokenTimes => "por",
+        SpokenMinus => "menos",
+        SpokenDivide => "dividido por",
+        SpokenBlank => "vacío",
+        SpokenRoman => "{0} romano",
+
+        HintNoOperator => "Sin operador",
+        HintTiles => "{0} casillas",
+        ChallengeTimed => "Amigo: {0} en {1}. ¡Supéralo!",
+        ChallengeUntimed => "Amigo: {0}. ¡Supéralo!",
+        PlayerShort => "J{0}",
+    }
+}
+
+fn french(text: Text) -> &'static str {
+    use Text::*;
+    match text {
+        Ok => "OK",
+        Share => "Partager",
+        ShareDialogTitle => "Partager Myriad",
+        NewGame => "Nouvelle partie",
+        Today => "JOUR",
+        Random => "HASARD",
+        History => "Historique",
+        Stats => "Stats",
+        Statistics => "Statistiques",
+        Calendar => "Calendrier",
+        Tutorial => "Tutoriel",
+        Export => "Exporter",
+        Import => "Importer",
+        CopyCode => "Copier le code",
+        PasteCode => "Coller le code",
+        ImportedGames => "{0} parties importées",
+        CodeCopied => "Code copié",
+        HistoryExported => "Historique exporté",
+
+        Settings => "Réglages",
+        Theme => "Thème",
+        ThemeAuto => "Auto",
+        ThemeLight => "Clair",
+        ThemeDark => "Sombre",
+        Language => "Langue",
+        HighContrast => "Contraste élevé",
+        Haptics => "Vibration",
+        Confetti => "Confettis",
+        DifficultyDots => "Points de difficulté",
+        ShowTimer => "Chrono",
+        LeftHanded => "Gaucher",
+        Analytics => "Données d'usage",
+        ShortestPathsOnly => "Chemins les plus courts",
+        PerfectGame => "Parfait",
+        On => "Oui",
+        Off => "Non",
+
+        WellDone100 => "Bravo, vous avez 💯 !",
+        TimeUp => "Temps écoulé ! Vous avez trouvé {0}",
+        PlayerWins => "Le joueur {0} gagne !",
+        Draw => "Égalité !",
+        YouCheated => "Vous avez triché !",
+        HintUsed => "1 indice utilisé",
+        HintsUsed => "{0} indices utilisés",
+        PerfectScore => "Score parfait",
+        Score => "Score",
+        SuboptimalSolution => "{0} solution améliorable",
+        SuboptimalSolutions => "{0} solutions améliorables",
+        HardSolution => "{0} solution difficile",
+        HardSolutions => "{0} solutions difficiles",
+
+        GamesPlayed => "Parties jouées",
+        Completed => "Terminées",
+        CurrentStreak => "Série actuelle",
+        LongestStreak => "Meilleure série",
+        AverageTime => "Temps moyen",
+        BestTime => "Meilleur temps",
+        FoundByDifficulty => "Trouvés par difficulté",
+        MostMissed => "Les plus manqués",
+        CalendarStreaks => "Série {0} · Record {1}",
+
+        Achievements => "Succès",
+        AchievementUnlocked => "Succès débloqué",
+        Locked => "Verrouillé",
+        AchievementHonest => "Honnête",
+        AchievementHonestDescription => "Trouvez tous les nombres sans tricher",
+        AchievementOptimal => "Raccourci",
+        AchievementOptimalDescription => "Trouvez tous les nombres par le chemin le plus court",
+        AchievementWeekStreak => "Semaine complète",
+        AchievementWeekStreakDescription => "Jouez au jeu du jour 7 jours de suite",
+        AchievementSpeedy => "Rapide",
+        AchievementSpeedyDescription => "Trouvez tous les nombres en moins de 10 minutes",
+
+        Replay => "Revoir",
+        NothingToReplay => "Rien à revoir",
+
+        WellDone => "Bravo !",
+        Next => "Suivant",
+        Play => "Jouer",
+        Skip => "Passer",
+        TutorialFirstNumber => "Glissez 3 → + → 4 pour faire 7",
+        TutorialDigitsJoin => {
+            "Les chiffres voisins se collent. Glissez 1 → 2 → + → 3 pour faire 15"
+        }
+        TutorialAdjacent => "Chaque case doit toucher la précédente, en diagonale aussi. Faites 7",
+        TutorialLeftToRight => {
+            "Les calculs se font de gauche à droite. Glissez 2 → + → 3 → × → 4 pour faire 20"
+        }
+        TutorialOnYourOwn => "À vous de jouer. Faites 9",
+
+        FoundNumber => "{0} trouvé",
+        AlreadyFound => "{0} déjà trouvé",
+        OutOfRange => "{0} est hors limites",
+        NotShortest => "{0} a un chemin plus court",
+        HasDifficulty => "{0} a une difficulté de {1}",
+        JoinedRace => "{0} a rejoint la course",
+        FoundFirst => "{0} a trouvé {1} en premier",
+        RaceUnavailable => "Les courses ne sont pas disponibles",
+
+        BoardLabel => "Plateau",
+        TileLabel => "{0}, ligne {1} colonne {2}",
+        SpokenPlus => "plus",
+        SpokenTimes => "fois",
+        SpokenMinus => "moins",
+        SpokenDivide => "divisé par",
+        SpokenBlank => "vide",
+        SpokenRoman => "{0} romain",
+
+        HintNoOperator => "Aucun opérateur",
+        HintTiles => "{0} cases",
+        ChallengeTimed => "Ami : {0} en {1}. Battez-le !",
+        ChallengeUntimed => "Ami : {0}. Battez-le !",
+        PlayerShort => "J{0}",
+    }
+}
+
+fn german(text: Text) -> &'static str {
+    use Text::*;
+    match text {
+        Ok => "OK",
+        Share => "Teilen",
+        ShareDialogTitle => "Myriad teilen",
+        NewGame => "Neues Spiel",
+        Today => "HEUTE",
+        Random => "ZUFALL",
+        History => "Verlauf",
+        Stats => "Statistik",
+        Statistics => "Statistik",
+        Calendar => "Kalender",
+        Tutorial => "Anleitung",
+        Export => "Exportieren",
+        Import => "Importieren",
+        CopyCode => "Code kopieren",
+        PasteCode => "Code einfügen",
+        ImportedGames => "{0} Spiele importiert",
+        CodeCopied => "Code kopiert",
+        HistoryExported => "Verlauf exportiert",
+
+        Settings => "Einstellungen",
+        Theme => "Design",
+        ThemeAuto => "Automatisch",
+        ThemeLight => "Hell",
+        ThemeDark => "Dunkel",
+        Language => "Sprache",
+        HighContrast => "Hoher Kontrast",
+        Haptics => "Vibration",
+        Confetti => "Konfetti",
+        DifficultyDots => "Schwierigkeitspunkte",
+        ShowTimer => "Timer",
+        LeftHanded => "Linkshänder",
+        Analytics => "Nutzungsdaten",
+        ShortestPathsOnly => "Nur kürzeste Wege",
+        PerfectGame => "Perfekt",
+        On => "An",
+        Off => "Aus",
+
+        WellDone100 => "Gut gemacht, du hast 💯!",
+        TimeUp => "Die Zeit ist um! Du hast {0} gefunden",
+        PlayerWins => "Spieler {0} gewinnt!",
+        Draw => "Unentschieden!",
+        YouCheated => "Du hast geschummelt!",
+        HintUsed => "1 Hinweis benutzt",
+        HintsUsed => "{0} Hinweise benutzt",
+        PerfectScore => "Perfekte Punktzahl",
+        Score => "Punkte",
+        SuboptimalSolution => "{0} verbesserbare Lösung",
+        SuboptimalSolutions => "{0} verbesserbare Lösungen",
+        HardSolution => "{0} schwere Lösung",
+        HardSolutions => "{0} schwere Lösungen",
+
+        GamesPlayed => "Gespielte Spiele",
+        Completed => "Abgeschlossen",
+        CurrentStreak => "Aktuelle Serie",
+        LongestStreak => "Längste Serie",
+        AverageTime => "Durchschnittszeit",
+        BestTime => "Bestzeit",
+        FoundByDifficulty => "Gefunden nach Schwierigkeit",
+        MostMissed => "Am häufigsten verpasst",
+        CalendarStreaks => "Serie {0} · Rekord {1}",
+
+        Achievements => "Erfolge",
+        AchievementUnlocked => "Erfolg freigeschaltet",
+        Locked => "Gesperrt",
+        AchievementHonest => "Ehrlich",
+        AchievementHonestDescription => "Finde alle Zahlen ohne zu schummeln",
+        AchievementOptimal => "Abkürzung",
+        AchievementOptimalDescription => "Finde alle Zahlen auf dem kürzesten Weg",
+        AchievementWeekStreak => "Wochenserie",
+        AchievementWeekStreakDescription => "Spiele das Tagesspiel 7 Tage in Folge",
+        AchievementSpeedy => "Schnell",
+        AchievementSpeedyDescription => "Finde alle Zahlen in unter 10 Minuten",
+
+        Replay => "Wiederholung",
+        NothingToReplay => "Nichts abzuspielen",
+
+        WellDone => "Gut gemacht!",
+        Next => "Weiter",
+        Play => "Spielen",
+        Skip => "Überspringen",
+        TutorialFirstNumber => "Ziehe 3 → + → 4, um 7 zu bilden",
+        TutorialDigitsJoin => {
+            "Benachbarte Ziffern verbinden sich. Ziehe 1 → 2 → + → 3, um 15 zu bilden"
+        }
+        TutorialAdjacent => "Jedes Feld muss das vorige berühren, auch diagonal. Bilde 7",
+        TutorialLeftToRight => {
+            "Gerechnet wird von links nach rechts. Ziehe 2 → + → 3 → × → 4, um 20 zu bilden"
+        }
+        TutorialOnYourOwn => "Jetzt bist du dran. Bilde 9",
+
+        FoundNumber => "{0} gefunden",
+        AlreadyFound => "{0} schon gefunden",
+        OutOfRange => "{0} liegt außerhalb des Bereichs",
+        NotShortest => "{0} hat einen kürzeren Weg",
+        HasDifficulty => "{0} hat Schwierigkeit {1}",
+        JoinedRace => "{0} ist dem Rennen beigetreten",
+        FoundFirst => "{0} hat {1} zuerst gefunden",
+        RaceUnavailable => "Rennen sind nicht verfügbar",
+
+        BoardLabel => "Spielbrett",
+        TileLabel => "{0}, Zeile {1} Spalte {2}",
+        SpokenPlus => "plus",
+        SpokenTimes => "mal",
+        SpokenMinus => "minus",
+        SpokenDivide => "geteilt durch",
+        SpokenBlank => "leer",
+        SpokenRoman => "römisch {0}",
+
+        HintNoOperator => "Kein Operator",
+        HintTiles => "{0} Felder",
+        ChallengeTimed => "Freund: {0} in {1}. Schlag das!",
+        ChallengeUntimed => "Freund: {0}. Schlag das!",
+        PlayerShort => "S{0}",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use strum::IntoEnumIterator;
+
+    fn placeholders(text: &str) -> Vec<usize> {
+        (0..10)
+            .filter(|i| text.contains(format!("{{{i}}}").as_str()))
+            .collect()
+    }
+
+    #[test]
+    fn test_translations_have_the_same_placeholders() {
+        for text in Text::iter() {
+            let expected = placeholders(Language::English.translate(text));
+            for language in Language::iter() {
+                let translated = language.translate(text);
+                assert!(!translated.is_empty(), "{language:?} {text:?}");
+                assert_eq!(placeholders(translated), expected, "{language:?} {text:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_translate_with() {
+        assert_eq!(
+            Language::English.translate_with(Text::FoundFirst, &[&"Alice", &7]),
+            "Alice found 7 first"
+        );
+        assert_eq!(
+            Language::German.translate_with(Text::ImportedGames, &[&3]),
+            "3 Spiele importiert"
+        );
+    }
+
+    #[test]
+    fn test_from_tag() {
+        assert_eq!(Language::from_tag("en-GB"), Some(Language::English));
+        assert_eq!(Language::from_tag("es_MX"), Some(Language::Spanish));
+        assert_eq!(Language::from_tag("FR"), Some(Language::French));
+        assert_eq!(Language::from_tag("ja-JP"), None);
+        assert_eq!(Language::from_tag(""), None);
+    }
+
+    #[test]
+    fn test_number_formatting() {
+        assert_eq!(Language::English.format_integer(1234567), "1,234,567");
+        assert_eq!(Language::German.format_integer(1234567), "1.234.567");
+        assert_eq!(Language::French.format_integer(12345), "12\u{202F}345");
+        assert_eq!(Language::Spanish.format_integer(-1234), "-1.234");
+        assert_eq!(Language::English.format_integer(999), "999");
+
+        assert_eq!(Language::English.format_percent(42), "42%");
+        assert_eq!(Language::French.format_percent(42), "42\u{00A0}%");
+    }
+}
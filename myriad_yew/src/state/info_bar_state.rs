@@ -1,8 +1,10 @@
 use std::rc::Rc;
 
-use myriad::prelude::Difficulty;
+use myriad::prelude::{Difficulty, Hint, Tile};
 use yewdux::store::{Reducer, Store};
 
+use super::prelude::*;
+
 #[derive(Clone, PartialEq, Eq, Debug, Default, Store)]
 pub enum InfoBarState {
     #[default]
@@ -10,7 +12,16 @@ pub enum InfoBarState {
     ValidNumber(i32),
     InvalidNumber(i32),
     Equation(String),
-    Difficulty(Difficulty),
+    Difficulty {
+        number: i32,
+        difficulty: Difficulty,
+    },
+    Hint {
+        number: i32,
+        hint: Hint<GRID_COLUMNS, GRID_ROWS>,
+        text: String,
+    },
+    Challenge(String),
 }
 
 impl InfoBarState {
@@ -20,7 +31,29 @@ impl InfoBarState {
             InfoBarState::ValidNumber(x) => x.to_string(),
             InfoBarState::InvalidNumber(x) => x.to_string(),
             InfoBarState::Equation(x) => x.clone(),
-            InfoBarState::Difficulty(d) => d.dots().to_string(),
+            InfoBarState::Difficulty { difficulty, .. } => difficulty.dots().to_string(),
+            InfoBarState::Hint { text, .. } => text.clone(),
+            InfoBarState::Challenge(text) => text.clone(),
+        }
+    }
+
+    /// The text to show, with the number instead of its difficulty if the player has turned difficulty off
+    pub fn display_text(&self, difficulty_dots: bool) -> String {
+        match self {
+            InfoBarState::Difficulty { number, .. } if !difficulty_dots => number.to_string(),
+            _ => self.text(),
+        }
+    }
+
+    /// The text for screen readers, with operators and difficulty spelled out
+    pub fn spoken_text(&self, language: Language) -> String {
+        match self {
+            InfoBarState::Equation(x) => spoken_equation(x, language),
+            InfoBarState::InvalidNumber(x) => language.translate_with(Text::OutOfRange, &[x]),
+            InfoBarState::Difficulty { number, difficulty } => {
+                language.translate_with(Text::HasDifficulty, &[number, &difficulty.0.get()])
+            }
+            _ => self.text(),
         }
     }
 
@@ -30,32 +63,54 @@ impl InfoBarState {
             InfoBarState::ValidNumber(_) => "var(--infobar-valid)",
             InfoBarState::InvalidNumber(_) => "var(--infobar-invalid)",
             InfoBarState::Equation(_) => "var(--infobar-equation)",
-            InfoBarState::Difficulty(_) => "var(--infobar-difficulty)",
+            InfoBarState::Difficulty { .. } => "var(--infobar-difficulty)",
+            InfoBarState::Hint { .. } => "var(--infobar-hint)",
+            InfoBarState::Challenge(_) => "var(--infobar-challenge)",
+        }
+    }
+
+    /// The number this is giving information about, if any
+    pub fn number(&self) -> Option<i32> {
+        match self {
+            InfoBarState::Difficulty { number, .. } | InfoBarState::Hint { number, .. } => {
+                Some(*number)
+            }
+            _ => None,
+        }
+    }
+
+    /// The tile revealed by the current hint, if any
+    pub fn hinted_tile(&self) -> Option<Tile<GRID_COLUMNS, GRID_ROWS>> {
+        match self {
+            InfoBarState::Hint { hint, .. } => hint.tile(),
+            _ => None,
         }
     }
 
     pub fn font_size(&self) -> &'static str {
         match self {
-            InfoBarState::Difficulty(d) => {
-                if d.0.get() <= 4 {
+            InfoBarState::Difficulty { difficulty, .. } => {
+                if difficulty.0.get() <= 4 {
                     "60px"
                 } else {
                     "30px"
                 }
             }
+            InfoBarState::Challenge(_) => "30px",
             _ => "60px",
         }
     }
 
     pub fn line_height(&self) -> &'static str {
         match self {
-            InfoBarState::Difficulty(d) => {
-                if d.0.get() <= 4 {
+            InfoBarState::Difficulty { difficulty, .. } => {
+                if difficulty.0.get() <= 4 {
                     "2"
                 } else {
                     "4"
                 }
             }
+            InfoBarState::Challenge(_) => "4",
             _ => "2",
         }
     }
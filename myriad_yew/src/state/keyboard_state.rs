@@ -0,0 +1,258 @@
+use crate::state::prelude::*;
+use myriad::parser::{parse_and_evaluate, ParseFail};
+use myriad::prelude::*;
+use std::rc::Rc;
+use yewdux::prelude::*;
+
+/// The tile highlighted by the keyboard cursor.
+/// The cursor is hidden until the arrow keys are first used.
+#[derive(PartialEq, Eq, Clone, Copy, Default, Store, Debug)]
+pub struct KeyboardFocusState {
+    pub focus: Option<Tile<GRID_COLUMNS, GRID_ROWS>>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KeyboardMsg {
+    /// Select the tile at this numpad position. 7 is the top left and 3 is the bottom right.
+    SelectNumpad(u8),
+    /// Move the cursor in this direction on the screen
+    MoveFocus(Vector),
+    /// Select the tile under the cursor
+    SelectFocused,
+    /// Remove the last tile from the path
+    Retrace,
+    /// Clear the path
+    Abandon,
+    /// Finish the current number and start a new one
+    Submit,
+    Undo,
+    Redo,
+}
+
+impl KeyboardMsg {
+    /// The message for a key, as given by `KeyboardEvent::key`
+    pub fn from_key(key: &str) -> Option<Self> {
+        let msg = match key {
+            "ArrowUp" => Self::MoveFocus(Vector::NORTH),
+            "ArrowDown" => Self::MoveFocus(Vector::SOUTH),
+            "ArrowLeft" => Self::MoveFocus(Vector::WEST),
+            "ArrowRight" => Self::MoveFocus(Vector::EAST),
+            " " => Self::SelectFocused,
+            "Backspace" => Self::Retrace,
+            "Escape" => Self::Abandon,
+            "Enter" => Self::Submit,
+            _ => {
+                let mut chars = key.chars();
+                let digit = chars.next()?.to_digit(10)?;
+                if chars.next().is_some() || digit == 0 {
+                    return None;
+                }
+                Self::SelectNumpad(digit as u8)
+            }
+        };
+        Some(msg)
+    }
+
+    /// The message for a key pressed while holding control or command
+    pub fn from_shortcut(key: &str) -> Option<Self> {
+        match key {
+            "z" => Some(Self::Undo),
+            "Z" | "y" | "Y" => Some(Self::Redo),
+            _ => None,
+        }
+    }
+}
+
+/// The tile shown at this numpad position with the board's current orientation
+pub fn tile_at_numpad(
+    numpad: u8,
+    rot_flip: &RotFlipState,
+) -> Option<Tile<GRID_COLUMNS, GRID_ROWS>> {
+    let index = numpad.checked_sub(1)?;
+    let screen = Tile::try_new(
+        index % GRID_COLUMNS,
+        (GRID_ROWS - 1).checked_sub(index / GRID_COLUMNS)?,
+    )?;
+    Some(tile_at_screen_position(&screen, rot_flip))
+}
+
+/// The tile which is shown at this position on the screen
+pub fn tile_at_screen_position(
+    screen: &Tile<GRID_COLUMNS, GRID_ROWS>,
+    rot_flip: &RotFlipState,
+) -> Tile<GRID_COLUMNS, GRID_ROWS> {
+    Tile::<GRID_COLUMNS, GRID_ROWS>::iter_by_row()
+        .find(|tile| rotate_and_flip(tile, rot_flip.rotate, rot_flip.flip) == *screen)
+        .unwrap_or(*screen)
+}
+
+/// The tile the cursor moves to, or the centre tile if the cursor is hidden
+pub fn move_focus(
+    focus: Option<Tile<GRID_COLUMNS, GRID_ROWS>>,
+    direction: &Vector,
+    rot_flip: &RotFlipState,
+) -> Tile<GRID_COLUMNS, GRID_ROWS> {
+    let Some(focus) = focus else {
+        return Tile::new_const::<1, 1>();
+    };
+
+    let screen = rotate_and_flip(&focus, rot_flip.rotate, rot_flip.flip);
+    match screen.const_add(direction) {
+        Some(new_screen) => tile_at_screen_position(&new_screen, rot_flip),
+        None => focus,
+    }
+}
+
+impl Reducer<KeyboardFocusState> for KeyboardMsg {
+    fn apply(self, state: Rc<KeyboardFocusState>) -> Rc<KeyboardFocusState> {
+        let rot_flip = Dispatch::<RotFlipState>::new().get();
+        let positions = Dispatch::<ChosenPositionsState>::new().get().positions;
+
+        let select = |tile: Tile<GRID_COLUMNS, GRID_ROWS>| {
+            Dispatch::<ChosenPositionsState>::new()
+                .apply(ChangeChosenPositionsMessage::Continue(tile));
+            Rc::new(KeyboardFocusState { focus: Some(tile) })
+        };
+
+        match self {
+            KeyboardMsg::SelectNumpad(numpad) => match tile_at_numpad(numpad, &rot_flip) {
+                Some(tile) => select(tile),
+                None => state,
+            },
+            KeyboardMsg::MoveFocus(direction) => KeyboardFocusState {
+                focus: Some(move_focus(state.focus, &direction, &rot_flip)),
+            }
+            .into(),
+            KeyboardMsg::SelectFocused => match state.focus {
+                Some(tile) => select(tile),
+                None => state,
+            },
+            KeyboardMsg::Retrace => {
+                match positions.len() {
+                    0 => {}
+                    1 => Dispatch::<ChosenPositionsState>::new()
+                        .apply(ChangeChosenPositionsMessage::Abandon),
+                    n => Dispatch::<ChosenPositionsState>::new()
+                        .apply(ChangeChosenPositionsMessage::Continue(positions[n - 2])),
+                }
+                state
+            }
+            KeyboardMsg::Abandon => {
+                Dispatch::<ChosenPositionsState>::new()
+                    .apply(ChangeChosenPositionsMessage::Abandon);
+                state
+            }
+            KeyboardMsg::Submit => {
+                let board = shown_board();
+                let mut letters = positions.iter().map(|c| board[*c]).peekable();
+
+                //Numbers are found as soon as they are made so submitting just starts a new number
+                if !matches!(
+                    parse_and_evaluate(&mut letters),
+                    Err(ParseFail::PartialSuccess)
+                ) {
+                    Dispatch::<ChosenPositionsState>::new()
+                        .apply(ChangeChosenPositionsMessage::Abandon);
+                }
+                state
+            }
+            KeyboardMsg::Undo => {
+                Dispatch::<FullGameState>::new().apply(UndoRedoMsg::Undo);
+                state
+            }
+            KeyboardMsg::Redo => {
+                Dispatch::<FullGameState>::new().apply(UndoRedoMsg::Redo);
+                state
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use myriad::prelude::QuarterTurns;
+
+    #[test]
+    fn test_keys() {
+        assert_eq!(
+            KeyboardMsg::from_key("7"),
+            Some(KeyboardMsg::SelectNumpad(7))
+        );
+        assert_eq!(KeyboardMsg::from_key("0"), None);
+        assert_eq!(KeyboardMsg::from_key("F1"), None);
+        assert_eq!(KeyboardMsg::from_key("Enter"), Some(KeyboardMsg::Submit));
+        assert_eq!(
+            KeyboardMsg::from_key("ArrowLeft"),
+            Some(KeyboardMsg::MoveFocus(Vector::WEST))
+        );
+        assert_eq!(KeyboardMsg::from_shortcut("z"), Some(KeyboardMsg::Undo));
+        assert_eq!(KeyboardMsg::from_shortcut("Z"), Some(KeyboardMsg::Redo));
+        assert_eq!(KeyboardMsg::from_shortcut("y"), Some(KeyboardMsg::Redo));
+        assert_eq!(KeyboardMsg::from_shortcut("7"), None);
+    }
+
+    #[test]
+    fn test_numpad_without_rotation() {
+        let rot_flip = RotFlipState::default();
+
+        assert_eq!(
+            tile_at_numpad(7, &rot_flip),
+            Some(Tile::new_const::<0, 0>())
+        );
+        assert_eq!(
+            tile_at_numpad(9, &rot_flip),
+            Some(Tile::new_const::<2, 0>())
+        );
+        assert_eq!(
+            tile_at_numpad(1, &rot_flip),
+            Some(Tile::new_const::<0, 2>())
+        );
+        assert_eq!(
+            tile_at_numpad(5, &rot_flip),
+            Some(Tile::new_const::<1, 1>())
+        );
+        assert_eq!(tile_at_numpad(0, &rot_flip), None);
+        assert_eq!(tile_at_numpad(10, &rot_flip), None);
+    }
+
+    #[test]
+    fn test_numpad_follows_orientation() {
+        for rotate in [
+            QuarterTurns::Zero,
+            QuarterTurns::One,
+            QuarterTurns::Two,
+            QuarterTurns::Three,
+        ] {
+            for flip in [false, true] {
+                let rot_flip = RotFlipState { rotate, flip };
+
+                for numpad in 1..=9 {
+                    let tile = tile_at_numpad(numpad, &rot_flip).unwrap();
+                    let screen = rotate_and_flip(&tile, rotate, flip);
+                    let index = numpad - 1;
+
+                    assert_eq!(screen.x(), index % 3);
+                    assert_eq!(screen.y(), 2 - (index / 3));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_move_focus() {
+        let rot_flip = RotFlipState {
+            rotate: QuarterTurns::One,
+            flip: false,
+        };
+        let centre = move_focus(None, &Vector::NORTH, &rot_flip);
+        assert_eq!(centre, Tile::new_const::<1, 1>());
+
+        let top = move_focus(Some(centre), &Vector::NORTH, &rot_flip);
+        let screen = rotate_and_flip(&top, rot_flip.rotate, rot_flip.flip);
+        assert_eq!((screen.x(), screen.y()), (1, 0));
+
+        let still_top = move_focus(Some(top), &Vector::NORTH, &rot_flip);
+        assert_eq!(still_top, top);
+    }
+}
@@ -1,16 +1,20 @@
+use std::cell::Cell;
+
 use capacitor_bindings::{
     app::AppInfo,
     device::{Device, DeviceInfo, OperatingSystem, Platform},
 };
+use myriad::prelude::HintLevel;
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
 use strum::EnumDiscriminants;
 use wasm_bindgen_futures::spawn_local;
 use yewdux::prelude::Dispatch;
 
-use crate::{state::prelude::*, web::capacitor};
+use crate::web::capacitor;
 
-use super::user_state::UserState;
+use super::preferences_state::PreferencesState;
+use super::event_pipeline::{EventQueueState, QueueEventMsg, QueuedEvent};
 
 #[derive(PartialEq, Eq, Clone, serde:: Serialize, serde::Deserialize, Debug)]
 #[serde(transparent)]
@@ -20,10 +24,112 @@ pub struct DeviceUUID(pub String);
 
 /// This token can only be used to ingest data into our bucket
 const API_TOKEN: &str = "xaat-3a2dca4d-ecbe-4b81-8d1c-1ef103b3ff42";
+const AXIOM_DATASET: &str = "myriadusage";
+
+/// Where to send logs. This can be set when building to `axiom`, `console`, `disabled`
+/// or the url of any endpoint which accepts a JSON array of logs, such as a local stand-in for testing.
+pub const LOG_BACKEND_SETTING: Option<&str> = option_env!("MYRIAD_LOG_BACKEND");
+
+/// The percentage of sessions which send gameplay events, such as numbers found.
+/// This can be set when building and defaults to every session.
+pub const LOG_SAMPLE_RATE_SETTING: Option<&str> = option_env!("MYRIAD_LOG_SAMPLE_RATE");
+
+thread_local! {
+    /// Decides whether this session is sampled. It is rolled once so a sampled session is recorded in full.
+    static SESSION_ROLL: u8 = rand::Rng::gen_range(&mut rand::thread_rng(), 0..100);
+    static SESSION_START: Cell<Option<i64>> = Cell::new(None);
+}
+
+/// The percentage of sessions to sample, from 0 to 100
+pub fn sample_rate_percent(setting: Option<&str>) -> u8 {
+    setting
+        .and_then(|x| x.trim().parse::<u8>().ok())
+        .map(|x| x.min(100))
+        .unwrap_or(100)
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LogBackend {
+    Axiom {
+        dataset: &'static str,
+        token: &'static str,
+    },
+    Http {
+        url: String,
+    },
+    /// Write logs to the browser console instead of sending them
+    Console,
+    Disabled,
+}
+
+impl LogBackend {
+    pub fn configured() -> Self {
+        Self::from_setting(LOG_BACKEND_SETTING)
+    }
+
+    pub fn from_setting(setting: Option<&str>) -> Self {
+        match setting.map(|x| x.trim()) {
+            None | Some("") | Some("axiom") => LogBackend::Axiom {
+                dataset: AXIOM_DATASET,
+                token: API_TOKEN,
+            },
+            Some("console") => LogBackend::Console,
+            Some("disabled") | Some("none") => LogBackend::Disabled,
+            Some(url) => LogBackend::Http {
+                url: url.to_string(),
+            },
+        }
+    }
+
+    /// Whether logs go over the network, so may need to be retried
+    pub fn is_remote(&self) -> bool {
+        matches!(self, LogBackend::Axiom { .. } | LogBackend::Http { .. })
+    }
+
+    pub async fn send(&self, logs: &[EventLog]) -> Result<(), reqwest::Error> {
+        if logs.is_empty() {
+            return Ok(());
+        }
+        match self {
+            LogBackend::Axiom { dataset, token } => {
+                let url = format!("https://api.axiom.co/v1/datasets/{dataset}/ingest");
+                Self::post(url.as_str(), Some(token), logs).await
+            }
+            LogBackend::Http { url } => Self::post(url.as_str(), None, logs).await,
+            LogBackend::Console => {
+                for log in logs {
+                    log::info!("{}", serde_json::to_string(log).unwrap_or_default());
+                }
+                Ok(())
+            }
+            LogBackend::Disabled => Ok(()),
+        }
+    }
+
+    async fn post(url: &str, token: Option<&str>, logs: &[EventLog]) -> Result<(), reqwest::Error> {
+        let mut request = reqwest::Client::new()
+            .post(url)
+            .header("Content-Type", "application/json");
+        if let Some(token) = token {
+            request = request.bearer_auth(token);
+        }
+        let res = request.json(logs).send().await?;
+
+        res.error_for_status().map(|_| ())
+    }
+}
+
+/// Whether events should be recorded at all
+pub fn is_logging_enabled() -> bool {
+    LogBackend::configured() != LogBackend::Disabled
+        && !Dispatch::<PreferencesState>::new().get().analytics_opt_out
+}
 
 #[derive(Debug, Clone, Serialize)]
 pub struct EventLog {
     pub user_id: DeviceUUID,
+    /// The same for every attempt to send this event, so the server can drop copies
+    pub id: u64,
     #[serde(skip_serializing_if = "is_false")]
     pub resent: bool,
     pub event: LoggableEvent,
@@ -47,10 +153,11 @@ pub enum Severity {
 }
 
 impl EventLog {
-    pub fn new_resent(user_id: DeviceUUID, event: LoggableEvent) -> Self {
+    pub fn new_resent(user_id: DeviceUUID, id: u64, event: LoggableEvent) -> Self {
         let severity = event.get_severity();
         Self {
             user_id,
+            id,
             resent: true,
             event,
             severity,
@@ -149,6 +256,38 @@ pub enum LoggableEvent {
     GameComplete {
         board: String,
     },
+    // Gameplay events are sampled and never record anything typed by the player, or any identifier other than the device's.
+    /// A number was found for the first time in this game
+    NumberFound {
+        board: String,
+        number: i32,
+        /// The number of tiles in the path, but not the path itself
+        path_length: usize,
+        /// Time since the game was started, if it is known
+        elapsed_milliseconds: Option<u64>,
+        /// How many numbers have been found including this one
+        found_count: usize,
+    },
+    HintUsed {
+        board: String,
+        number: i32,
+        level: HintLevel,
+    },
+    /// A solution was revealed
+    CheatUsed {
+        board: String,
+        number: i32,
+    },
+    /// The player moved to another game without finishing this one
+    GameAbandoned {
+        board: String,
+        found: usize,
+        total: usize,
+    },
+    /// The page was closed or hidden
+    SessionEnded {
+        milliseconds: u64,
+    },
     Warn {
         message: String,
     },
@@ -197,23 +336,35 @@ impl LoggableEvent {
         Self::try_log_async(self).await
     }
 
-    /// Either logs the message or sends it to be retried later
+    /// Queue the event to be sent with the next batch
     pub async fn try_log_async(data: impl Into<Self>) {
-        let user = Dispatch::<UserState>::new().get();
-        let event = data.into();
-        let severity = event.get_severity();
-        if let Some(user_id) = &user.user_id1 {
-            let message = EventLog {
-                event,
-                user_id: user_id.clone(),
-                resent: false,
-                severity,
-            };
-            message.send_log_async().await;
-        } else {
-            Dispatch::<FailedLogsState>::new().apply(LogFailedMessage(event));
-            log::error!("User Id not set");
+        if !is_logging_enabled() {
+            return;
+        }
+        let event: Self = data.into();
+        let rate = sample_rate_percent(LOG_SAMPLE_RATE_SETTING);
+        if !SESSION_ROLL.with(|roll| event.should_send(*roll, rate)) {
+            return;
         }
+        Dispatch::<EventQueueState>::new().apply(QueueEventMsg(QueuedEvent::new(event)));
+    }
+
+    /// Whether this event is only sent from a sample of sessions.
+    /// New users, completed games and errors are always sent.
+    pub fn is_sampled(&self) -> bool {
+        matches!(
+            self,
+            LoggableEvent::NumberFound { .. }
+                | LoggableEvent::HintUsed { .. }
+                | LoggableEvent::CheatUsed { .. }
+                | LoggableEvent::GameAbandoned { .. }
+                | LoggableEvent::SessionEnded { .. }
+        )
+    }
+
+    /// Whether to send this event from a session which rolled `roll` out of 100
+    pub fn should_send(&self, roll: u8, sample_rate_percent: u8) -> bool {
+        !self.is_sampled() || roll < sample_rate_percent
     }
 
     pub fn try_log1(self){
@@ -235,33 +386,122 @@ impl LoggableEvent {
 
 }
 
+/// Remember when this session started
+pub fn start_session(now_utc_milliseconds: i64) {
+    SESSION_START.with(|x| x.set(Some(now_utc_milliseconds)));
+}
+
+/// Log the length of the session so far and start a new one, as the page may not be shown again
+pub fn end_session(now_utc_milliseconds: i64) {
+    let Some(start) = SESSION_START.with(|x| x.replace(None)) else {
+        return;
+    };
+    let milliseconds = now_utc_milliseconds.saturating_sub(start);
+    if milliseconds.is_positive() {
+        LoggableEvent::SessionEnded {
+            milliseconds: milliseconds.unsigned_abs(),
+        }
+        .try_log1();
+    }
+}
+
 impl EventLog {
     pub async fn send_log_async(self) {
         Self::log_async(self).await
     }
 
-    async fn try_log<T: Serialize>(data: &T) -> Result<(), reqwest::Error> {
-        let client = reqwest::Client::new();
-        let res = client
-            .post("https://api.axiom.co/v1/datasets/myriadusage/ingest")
-            // .header("Authorization", format!("Bearer {API_TOKEN}"))
-            .bearer_auth(API_TOKEN)
-            .header("Content-Type", "application/json")
-            .json(&[data])
-            .send()
-            .await?;
-
-        res.error_for_status().map(|_| ())
-    }
-
     async fn log_async(data: Self) {
-        let r = Self::try_log(&data).await;
+        if !is_logging_enabled() {
+            return;
+        }
+        let backend = LogBackend::configured();
+        let r = backend.send(std::slice::from_ref(&data)).await;
         if let Err(err) = r {
             log::error!("Failed to log: {}", err);
-            Dispatch::<FailedLogsState>::new().apply(LogFailedMessage(data.event));
+            Dispatch::<EventQueueState>::new().apply(QueueEventMsg(QueuedEvent {
+                id: data.id,
+                event: data.event,
+                resent: true,
+            }));
         } else {
             let discriminant: LoggableEvent = data.event;
             log::debug!("Log {discriminant:?} sent successfully",);
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_log_backend_setting() {
+        assert!(matches!(
+            LogBackend::from_setting(None),
+            LogBackend::Axiom {
+                dataset: AXIOM_DATASET,
+                ..
+            }
+        ));
+        assert_eq!(LogBackend::from_setting(Some("console")), LogBackend::Console);
+        assert_eq!(LogBackend::from_setting(Some("disabled")), LogBackend::Disabled);
+        assert_eq!(
+            LogBackend::from_setting(Some(" http://localhost:8080/ingest ")),
+            LogBackend::Http {
+                url: "http://localhost:8080/ingest".to_string()
+            }
+        );
+
+        assert!(LogBackend::from_setting(Some("http://localhost")).is_remote());
+        assert!(!LogBackend::Console.is_remote());
+    }
+
+    #[test]
+    fn test_sampling() {
+        assert_eq!(sample_rate_percent(None), 100);
+        assert_eq!(sample_rate_percent(Some(" 25 ")), 25);
+        assert_eq!(sample_rate_percent(Some("250")), 100);
+        assert_eq!(sample_rate_percent(Some("some")), 100);
+
+        let found = LoggableEvent::NumberFound {
+            board: "123456789".to_string(),
+            number: 42,
+            path_length: 3,
+            elapsed_milliseconds: Some(1_000),
+            found_count: 1,
+        };
+        assert!(found.should_send(24, 25));
+        assert!(!found.should_send(25, 25));
+        assert!(!found.should_send(0, 0));
+
+        let error = LoggableEvent::Error {
+            message: "Oops".to_string(),
+        };
+        assert!(error.should_send(99, 0));
+    }
+
+    #[test]
+    fn test_gameplay_event_schema() {
+        let event = LoggableEvent::GameAbandoned {
+            board: "123456789".to_string(),
+            found: 12,
+            total: 100,
+        };
+        assert_eq!(
+            serde_json::to_string(&event).unwrap(),
+            r#"{"type":"GameAbandoned","board":"123456789","found":12,"total":100}"#
+        );
+
+        let event = LoggableEvent::NumberFound {
+            board: "123456789".to_string(),
+            number: 7,
+            path_length: 3,
+            elapsed_milliseconds: None,
+            found_count: 1,
+        };
+        assert_eq!(
+            serde_json::to_string(&event).unwrap(),
+            r#"{"type":"NumberFound","board":"123456789","number":7,"path_length":3,"found_count":1}"#
+        );
+    }
+}
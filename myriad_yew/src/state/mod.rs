@@ -1,25 +1,48 @@
+pub mod accessibility;
+pub mod achievements;
+pub mod action_log;
+pub mod calendar;
+pub mod challenge_state;
 pub mod chosen_positions_state;
 pub mod circle_type;
 pub mod dialog_state;
+pub mod event_pipeline;
 pub mod failed_logs_state;
 pub mod found_words_state;
 pub mod full_game_state;
 pub mod game_size;
+pub mod history_code;
 pub mod history_state;
+pub mod i18n;
+pub mod hints_state;
 pub mod info_bar_state;
+pub mod keyboard_state;
 pub mod logging;
 pub mod msg;
+pub mod multiplayer;
 pub mod preferences_state;
+pub mod race_client;
 pub mod recent_word_state;
+pub mod replay;
 pub mod rot_flip_state;
 pub mod selected_tab_state;
+pub mod share_text;
+pub mod stats;
+pub mod suggestions_state;
+pub mod timed_mode;
+pub mod tutorial;
 pub mod user_state;
 pub mod util;
+pub mod versioned_storage;
 pub mod game_timing;
 pub mod game_rating;
 
 pub mod prelude {
 
+    pub use crate::state::accessibility::*;
+    pub use crate::state::achievements::*;
+    pub use crate::state::action_log::*;
+    pub use crate::state::challenge_state::*;
     pub use crate::state::chosen_positions_state::*;
     pub use crate::state::circle_type::*;
     pub use crate::state::dialog_state::*;
@@ -27,11 +50,20 @@ pub mod prelude {
     pub use crate::state::full_game_state::*;
     pub use crate::state::game_size::*;
     pub use crate::state::history_state::*;
+    pub use crate::state::hints_state::*;
+    pub use crate::state::i18n::*;
+    pub use crate::state::keyboard_state::*;
     pub use crate::state::msg::*;
+    pub use crate::state::multiplayer::*;
+    pub use crate::state::race_client::*;
     pub use crate::state::recent_word_state::*;
+    pub use crate::state::replay::*;
     pub use crate::state::rot_flip_state::*;
     pub use crate::state::selected_tab_state::*;
+    pub use crate::state::suggestions_state::*;
     pub use crate::state::game_timing::*;
+    pub use crate::state::timed_mode::*;
+    pub use crate::state::tutorial::*;
     pub use crate::state::util::*;
 
     pub use crate::state::failed_logs_state::*;
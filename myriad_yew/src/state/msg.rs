@@ -1,3 +1,4 @@
+use crate::state::preferences_state::PreferencesState;
 use crate::state::prelude::*;
 use crate::web::prelude::*;
 use myriad::{parser, prelude::*};
@@ -30,6 +31,16 @@ impl Reducer<FullGameState> for LoadGameMessage {
         Dispatch::<ChosenPositionsState>::new().reduce_mut(|s| s.positions.clear());
         Dispatch::<RotFlipState>::new().reduce_mut(|x| x.clear());
 
+        let (found, total) = previous.get_found_count();
+        if found > 0 && found < total {
+            LoggableEvent::GameAbandoned {
+                board: previous.game.board.canonical_string(),
+                found,
+                total,
+            }
+            .try_log1();
+        }
+
         Dispatch::<HistoryState>::new().apply(SaveGameMessage(previous.into()));
 
         match loaded {
@@ -38,6 +49,15 @@ impl Reducer<FullGameState> for LoadGameMessage {
                 game: self.game,
                 timing: Default::default(),
                 found_words: Default::default(),
+                hints: Default::default(),
+                timed: TimedGame::new(Dispatch::<TimedModeState>::new().get().mode),
+                actions: Default::default(),
+                events: Default::default(),
+                played: None,
+                shortest_paths_only: Dispatch::<PreferencesState>::new()
+                    .get()
+                    .shortest_paths_only,
+                score: Default::default(),
             }),
         }
     }
@@ -49,16 +69,35 @@ pub fn move_to_new_game(for_today: bool, navigator: &Navigator) {
         return; //Do nothing
     }
 
-    Dispatch::<RecentWordState>::new().reduce_mut(|s| s.recent_words.clear());
-    Dispatch::<ChosenPositionsState>::new().reduce_mut(|s| s.positions.clear());
-
-    Dispatch::<HistoryState>::new().apply(SaveGameMessage(previous));
-
     let game = if for_today {
         Game::create_for_today()
     } else {
         Game::create_random()
     };
+
+    move_to_game(previous, game, for_today, navigator)
+}
+
+/// Open the daily puzzle for a past date
+pub fn move_to_daily_game(date: chrono::NaiveDate, navigator: &Navigator) {
+    let previous: Rc<FullGameState> = Dispatch::new().get();
+    if previous.game.date == Some(date) {
+        navigator.push(&Route::Game {
+            game: previous.game.board.canonical_string(),
+        });
+        return;
+    }
+
+    let game = Game::create_for_date(date);
+    move_to_game(previous, game, false, navigator)
+}
+
+fn move_to_game(previous: Rc<FullGameState>, game: Game, for_today: bool, navigator: &Navigator) {
+    Dispatch::<RecentWordState>::new().reduce_mut(|s| s.recent_words.clear());
+    Dispatch::<ChosenPositionsState>::new().reduce_mut(|s| s.positions.clear());
+
+    Dispatch::<HistoryState>::new().apply(SaveGameMessage(previous));
+
     let game_string = game.board.canonical_string();
 
     let event = logging::LoggableEvent::NewGame {
@@ -67,9 +106,7 @@ pub fn move_to_new_game(for_today: bool, navigator: &Navigator) {
     };
     event.try_log1();
 
-    Dispatch::<FullGameState>::new().apply(LoadGameMessage {
-        game,
-    });
+    Dispatch::<FullGameState>::new().apply(LoadGameMessage { game });
 
     navigator.push(&Route::Game { game: game_string })
 }
@@ -96,11 +133,24 @@ fn get_emoji(i: i32) -> String {
 }
 
 impl Reducer<FullGameState> for OnCoordinatesSetMsg {
-    fn apply(self, state: Rc<FullGameState>) -> Rc<FullGameState> {
+    fn apply(self, mut state: Rc<FullGameState>) -> Rc<FullGameState> {
         let coordinates = self.coordinates;
-        if coordinates.is_empty() {
+        if coordinates.is_empty() || state.timed.is_over() {
             return state;
         }
+        //Tutorial boards are checked by the tutorial itself
+        if Dispatch::<TutorialState>::new().get().is_active() {
+            return state;
+        }
+
+        let now = now_utc_milliseconds();
+        if state.timed.mode == TimedMode::Countdown && state.timed.countdown_started.is_none() {
+            Rc::make_mut(&mut state).timed = state.timed.with_countdown_started(now);
+        }
+        if state.timed.countdown_remaining(now) == Some(0) {
+            return CountdownExpiredMsg.apply(state);
+        }
+        let elapsed = state.timing.elapsed_milliseconds(now);
 
         let mut letters = coordinates.iter().map(|c| state.game.board[*c]).peekable();
         let parse_result = parser::parse_and_evaluate(&mut letters);
@@ -113,6 +163,8 @@ impl Reducer<FullGameState> for OnCoordinatesSetMsg {
             let word_type = if state.game.solve_settings.allow(num) {
                 if state.found_words.has_word(&found_word) {
                     FoundWordType::PreviouslyFound
+                } else if state.shortest_paths_only && !state.is_shortest_path(&found_word) {
+                    FoundWordType::NotShortest
                 } else {
                     FoundWordType::Found
                 }
@@ -122,16 +174,51 @@ impl Reducer<FullGameState> for OnCoordinatesSetMsg {
 
             let timing: GameTiming;
             let new_found_words: Rc<FoundWordsTracker>;
+            let mut timed = state.timed.clone();
+            let mut actions = state.actions.clone();
+            let mut events = state.events.clone();
+            let mut played = state.played;
 
             match word_type {
                 FoundWordType::Found => {
                     let number = found_word.result;
+                    played = played.or_else(|| Some(Game::get_today_date()));
                     Dispatch::new().apply(NumberFoundMsg { number });
-                    let ns = state.found_words.with_word(found_word);
+                    Dispatch::new().apply(CreditNumberMsg {
+                        number,
+                        board: state.game.board.clone(),
+                    });
+                    submit_race_path(&state.game.board, &found_word.path);
+                    let preferences = Dispatch::<PreferencesState>::new().get();
+                    if preferences.haptics {
+                        crate::web::capacitor::do_or_report_error(|| {
+                            capacitor_bindings::haptics::Haptics::impact(
+                                capacitor_bindings::haptics::ImpactStyle::Light,
+                            )
+                        });
+                    }
+                    let event = FoundWordEvent {
+                        word: found_word.clone(),
+                        utc_time_milliseconds: now_utc_milliseconds(),
+                    };
+                    events.push(event.clone());
+                    let path_length = found_word.path.len();
+                    let ns = state.found_words.with_word(found_word.clone());
 
                     let len = ns.words.len();
+                    LoggableEvent::NumberFound {
+                        board: state.game.board.canonical_string(),
+                        number,
+                        path_length,
+                        elapsed_milliseconds: elapsed,
+                        found_count: len,
+                    }
+                    .try_log1();
+                    if let Some(elapsed) = elapsed {
+                        timed = timed.with_split(len, elapsed);
+                    }
 
-                    if len % 10 == 0 {
+                    if len % 10 == 0 && preferences.confetti {
                         make_confetti(get_emoji(len as i32 / 10), (10 + len) as i32);
                     }
 
@@ -145,13 +232,15 @@ impl Reducer<FullGameState> for OnCoordinatesSetMsg {
                             s.congratulations_dialog_type = Some(CongratsDialogType::OneHundred)
                         });
 
+                        if timed.mode == TimedMode::Countdown {
+                            timed = timed.with_countdown_ended(len);
+                        }
+
                         timing = match state.timing {
                             GameTiming::Started {
                                 utc_time_milliseconds,
                             } => {
-                                let js_today = js_sys::Date::new_0();
-                                let utc_time = js_today.get_time();
-                                let now_time_milliseconds = utc_time.floor() as i64;
+                                let now_time_milliseconds = now_utc_milliseconds();
 
                                 let difference =
                                     now_time_milliseconds.saturating_sub(utc_time_milliseconds);
@@ -173,16 +262,43 @@ impl Reducer<FullGameState> for OnCoordinatesSetMsg {
                         timing = state.timing;
                     }
 
+                    actions = actions.with_action(GameAction::FindWord {
+                        previous: None,
+                        word: found_word,
+                        effects: FindEffects {
+                            event: Some(event),
+                            split: timed
+                                .splits
+                                .last()
+                                .filter(|_| timed.splits.len() > state.timed.splits.len())
+                                .copied(),
+                            finished: (timing != state.timing).then_some((state.timing, timing)),
+                            played: played.filter(|_| state.played.is_none()),
+                        },
+                    });
                     new_found_words = ns.into();
                 }
                 FoundWordType::PreviouslyFound => {
                     //update if the word is shorter
-                    if let Some(_) = state
+                    if let Some(previous) = state
                         .found_words
                         .words
                         .get(&num)
                         .filter(|x| x.path.len() > coordinates.len())
                     {
+                        let event = FoundWordEvent {
+                            word: found_word.clone(),
+                            utc_time_milliseconds: now_utc_milliseconds(),
+                        };
+                        actions = actions.with_action(GameAction::FindWord {
+                            previous: Some(previous.clone()),
+                            word: found_word.clone(),
+                            effects: FindEffects {
+                                event: Some(event.clone()),
+                                ..Default::default()
+                            },
+                        });
+                        events.push(event);
                         new_found_words = state.found_words.with_word(found_word).into();
                     } else {
                         new_found_words = state.found_words.clone();
@@ -190,7 +306,7 @@ impl Reducer<FullGameState> for OnCoordinatesSetMsg {
 
                     timing = state.timing;
                 }
-                FoundWordType::NotInRange => {
+                FoundWordType::NotShortest | FoundWordType::NotInRange => {
                     new_found_words = state.found_words.clone();
                     timing = state.timing;
                 }
@@ -206,7 +322,15 @@ impl Reducer<FullGameState> for OnCoordinatesSetMsg {
                 game: state.game.clone(),
                 found_words: new_found_words,
                 timing,
+                hints: state.hints.clone(),
+                timed,
+                actions,
+                events,
+                played,
+                shortest_paths_only: state.shortest_paths_only,
+                score: Default::default(),
             }
+            .with_score()
             .into()
         } else {
             state
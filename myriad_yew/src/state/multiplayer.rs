@@ -0,0 +1,258 @@
+use crate::state::prelude::*;
+use myriad::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::rc::Rc;
+use yewdux::prelude::*;
+
+pub const MAX_PLAYERS: usize = 4;
+
+/// Players taking turns on one device.
+/// Each turn is one path and every number found on that path is credited to the player.
+#[derive(PartialEq, Eq, Clone, Serialize, Deserialize, Store, Debug)]
+#[store(storage = "local")]
+pub struct MultiplayerState {
+    /// The board the credits are for
+    pub board: Option<Board<GRID_COLUMNS, GRID_ROWS, GRID_SIZE>>,
+    pub players: usize,
+    /// The player whose turn it is
+    pub current: usize,
+    /// The player who found each number
+    pub credits: BTreeMap<i32, usize>,
+}
+
+impl Default for MultiplayerState {
+    fn default() -> Self {
+        Self {
+            board: None,
+            players: 1,
+            current: 0,
+            credits: Default::default(),
+        }
+    }
+}
+
+impl MultiplayerState {
+    pub fn is_active(&self) -> bool {
+        self.players > 1
+    }
+
+    /// The credits for this board
+    pub fn credits_for_board(
+        &self,
+        board: &Board<GRID_COLUMNS, GRID_ROWS, GRID_SIZE>,
+    ) -> Option<&BTreeMap<i32, usize>> {
+        (self.board.as_ref() == Some(board)).then_some(&self.credits)
+    }
+
+    /// The player who found this number on this board
+    pub fn credited_player(
+        &self,
+        number: i32,
+        board: &Board<GRID_COLUMNS, GRID_ROWS, GRID_SIZE>,
+    ) -> Option<usize> {
+        self.credits_for_board(board)?.get(&number).copied()
+    }
+
+    /// Change the number of players.
+    /// Starting with two players begins a new session and clears the credits.
+    pub fn with_players(
+        &self,
+        players: usize,
+        board: &Board<GRID_COLUMNS, GRID_ROWS, GRID_SIZE>,
+    ) -> Self {
+        let players = players.clamp(1, MAX_PLAYERS);
+        if players == 2 && !self.is_active() {
+            return Self {
+                board: Some(board.clone()),
+                players,
+                current: 0,
+                credits: Default::default(),
+            };
+        }
+
+        Self {
+            players,
+            current: self.current % players,
+            ..self.clone()
+        }
+    }
+
+    /// Credit a number to the player whose turn it is
+    pub fn with_credit(
+        &self,
+        number: i32,
+        board: &Board<GRID_COLUMNS, GRID_ROWS, GRID_SIZE>,
+    ) -> Self {
+        let mut result = self.clone();
+        if result.board.as_ref() != Some(board) {
+            result.board = Some(board.clone());
+            result.credits.clear();
+        }
+        result.credits.entry(number).or_insert(self.current);
+        result
+    }
+
+    pub fn with_turn_passed(&self) -> Self {
+        Self {
+            current: (self.current + 1) % self.players.max(1),
+            ..self.clone()
+        }
+    }
+
+    /// The number of words still found by each player who has taken part
+    pub fn scores(&self, found_words: &FoundWordsTracker) -> Vec<usize> {
+        let players = self
+            .credits
+            .values()
+            .map(|x| x + 1)
+            .fold(self.players, usize::max);
+        let mut scores = vec![0; players];
+
+        for (number, player) in self.credits.iter() {
+            if found_words.words.contains_key(number) {
+                scores[*player] += 1;
+            }
+        }
+        scores
+    }
+}
+
+/// The players with the highest score
+pub fn winners(scores: &[usize]) -> Vec<usize> {
+    let best = scores.iter().max().copied().unwrap_or_default();
+    (0..scores.len()).filter(|x| scores[*x] == best).collect()
+}
+
+/// Change between one and four players.
+/// The scoreboard is shown when a session ends.
+pub struct CyclePlayersMsg;
+
+impl Reducer<MultiplayerState> for CyclePlayersMsg {
+    fn apply(self, state: Rc<MultiplayerState>) -> Rc<MultiplayerState> {
+        let board = Dispatch::<FullGameState>::new().get().game.board.clone();
+        let new_state = state.with_players((state.players % MAX_PLAYERS) + 1, &board);
+
+        if !new_state.is_active()
+            && new_state
+                .credits_for_board(&board)
+                .is_some_and(|x| !x.is_empty())
+        {
+            Dispatch::<DialogState>::new().reduce_mut(|s| {
+                s.congratulations_dialog_type = Some(CongratsDialogType::Scoreboard)
+            });
+        }
+
+        new_state.into()
+    }
+}
+
+/// Credit a newly found number to the player whose turn it is
+pub struct CreditNumberMsg {
+    pub number: i32,
+    pub board: Board<GRID_COLUMNS, GRID_ROWS, GRID_SIZE>,
+}
+
+impl Reducer<MultiplayerState> for CreditNumberMsg {
+    fn apply(self, state: Rc<MultiplayerState>) -> Rc<MultiplayerState> {
+        if !state.is_active() {
+            return state;
+        }
+        state.with_credit(self.number, &self.board).into()
+    }
+}
+
+/// Let the next player take a turn
+pub struct PassTurnMsg;
+
+impl Reducer<MultiplayerState> for PassTurnMsg {
+    fn apply(self, state: Rc<MultiplayerState>) -> Rc<MultiplayerState> {
+        if !state.is_active() {
+            return state;
+        }
+        state.with_turn_passed().into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn board() -> Board<GRID_COLUMNS, GRID_ROWS, GRID_SIZE> {
+        Board::try_create("1+536-249").unwrap()
+    }
+
+    fn found(numbers: &[i32]) -> FoundWordsTracker {
+        let board = board();
+        numbers
+            .iter()
+            .map(|x| board.find_shortest(*x, &[]).unwrap())
+            .fold(FoundWordsTracker::default(), |acc, word| {
+                acc.with_word(word)
+            })
+    }
+
+    #[test]
+    fn test_turns_and_credits() {
+        let board = board();
+        let state = MultiplayerState::default().with_players(3, &board);
+        assert!(state.is_active());
+
+        let state = state
+            .with_credit(1, &board)
+            .with_credit(2, &board)
+            .with_turn_passed()
+            .with_credit(3, &board)
+            .with_credit(1, &board)
+            .with_turn_passed()
+            .with_turn_passed()
+            .with_credit(4, &board);
+
+        assert_eq!(state.current, 0);
+        assert_eq!(state.credited_player(1, &board), Some(0));
+        assert_eq!(state.credited_player(3, &board), Some(1));
+        assert_eq!(state.credited_player(5, &board), None);
+
+        assert_eq!(state.scores(&found(&[1, 2, 3, 4])), vec![3, 1, 0]);
+        assert_eq!(state.scores(&found(&[3])), vec![0, 1, 0]);
+    }
+
+    #[test]
+    fn test_starting_a_session_clears_credits() {
+        let board = board();
+        let state = MultiplayerState::default()
+            .with_players(2, &board)
+            .with_credit(1, &board)
+            .with_players(3, &board);
+        assert_eq!(state.credits.len(), 1);
+
+        let ended = state.with_players(1, &board);
+        assert!(!ended.is_active());
+        assert_eq!(ended.scores(&found(&[1])), vec![1]);
+
+        let restarted = ended.with_players(2, &board);
+        assert!(restarted.credits.is_empty());
+        assert_eq!(restarted.scores(&found(&[1])), vec![0, 0]);
+    }
+
+    #[test]
+    fn test_credits_are_per_board() {
+        let board = board();
+        let other = Board::try_create("1+536-248").unwrap();
+        let state = MultiplayerState::default()
+            .with_players(2, &board)
+            .with_credit(1, &board);
+
+        assert_eq!(state.credited_player(1, &other), None);
+
+        let state = state.with_credit(2, &other);
+        assert_eq!(state.credited_player(1, &other), None);
+        assert_eq!(state.credited_player(2, &other), Some(0));
+    }
+
+    #[test]
+    fn test_winners() {
+        assert_eq!(winners(&[3, 5, 1]), vec![1]);
+        assert_eq!(winners(&[4, 2, 4, 0]), vec![0, 2]);
+    }
+}
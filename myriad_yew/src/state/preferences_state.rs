@@ -1,34 +1,40 @@
 use std::rc::Rc;
 
+use crate::state::full_game_state::FullGameState;
+use crate::state::game_size::GameSize;
+use crate::state::i18n::{Language, Text};
+use crate::state::versioned_storage::*;
+use serde::{Deserialize, Serialize};
+use strum::EnumIter;
 use web_sys::window;
-#[cfg(target_arch = "wasm32")]
-use yewdux::storage;
-use yewdux::{
-    prelude::{init_listener, Listener},
-    store::{Reducer, Store},
-};
-
-fn update_window_dark_state(state: DarkModeState) -> Option<()> {
-    log::info!("Setting Dark");
+use yewdux::prelude::*;
 
+fn update_window_classes(state: &PreferencesState) -> Option<()> {
     let window = window()?;
     let document = window.document()?;
     let body = document.body()?;
     let root = body.parent_element()?;
-    let class_name = root.class_name();
+    let class_name = root
+        .class_name()
+        .replace("high-contrast", "")
+        .replace("dark", "")
+        .replace("light", "");
+    let mut class_name = class_name.split_whitespace().collect::<Vec<_>>().join(" ");
 
-    let fixed_class_name = class_name.replace("dark", "").replace("light", "");
-    let suffix = match state {
-        DarkModeState::Auto => "",
-        DarkModeState::Light => " light",
-        DarkModeState::Dark => " dark",
-    };
+    match state.theme {
+        DarkModeState::Auto => {}
+        DarkModeState::Light => class_name.push_str(" light"),
+        DarkModeState::Dark => class_name.push_str(" dark"),
+    }
+    if state.high_contrast {
+        class_name.push_str(" high-contrast");
+    }
 
-    root.set_class_name(format!("{fixed_class_name}{suffix}").as_str());
+    root.set_class_name(class_name.trim());
     Some(())
 }
 
-#[derive(Default, PartialEq, Eq, Clone, Copy, serde:: Serialize, serde::Deserialize, Debug)]
+#[derive(Default, PartialEq, Eq, Clone, Copy, serde::Serialize, serde::Deserialize, Debug)]
 pub enum DarkModeState {
     #[default]
     Auto,
@@ -36,27 +42,64 @@ pub enum DarkModeState {
     Dark,
 }
 
-impl Store for DarkModeState {
-    #[cfg(not(target_arch = "wasm32"))]
-    fn new() -> Self {
-        init_listener(DarkModeListener);
-        let state: DarkModeState = Default::default();
-        update_window_dark_state(state);
-        state
+/// Everything the player can change on the settings page
+#[derive(PartialEq, Eq, Clone, Debug, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PreferencesState {
+    pub theme: DarkModeState,
+    /// Stronger colours and thicker outlines. This works with both light and dark mode.
+    pub high_contrast: bool,
+    /// Vibrate when a number is found
+    pub haptics: bool,
+    /// Celebrate every tenth number
+    pub confetti: bool,
+    /// Show how hard an unfound number is when it is tapped
+    pub difficulty_dots: bool,
+    /// Show the countdown and the time taken
+    pub show_timer: bool,
+    /// Put the found words to the left of the board in landscape
+    pub left_handed: bool,
+    /// New games only count numbers found with the shortest possible path
+    pub shortest_paths_only: bool,
+    pub analytics_opt_out: bool,
+    /// `None` follows the device language
+    pub language: Option<Language>,
+}
+
+impl Default for PreferencesState {
+    fn default() -> Self {
+        Self {
+            theme: DarkModeState::Auto,
+            high_contrast: false,
+            haptics: true,
+            confetti: true,
+            difficulty_dots: true,
+            show_timer: true,
+            left_handed: false,
+            shortest_paths_only: false,
+            analytics_opt_out: false,
+            language: None,
+        }
     }
+}
 
-    #[cfg(target_arch = "wasm32")]
+impl PreferencesState {
+    /// The language chosen by the player, or else the device language
+    pub fn current_language(&self) -> Language {
+        self.language.or_else(Language::device).unwrap_or_default()
+    }
+}
 
+impl Store for PreferencesState {
     fn new() -> Self {
-        log::info!("Loading Dark Mode State");
-        init_listener(DarkModeListener);
-
-        let state: DarkModeState = storage::load(storage::Area::Local)
-            .ok()
-            .flatten()
-            .unwrap_or_default();
+        init_listener(PreferencesListener);
+        let state: Self = init_versioned_store();
+        #[cfg(target_arch = "wasm32")]
+        let state = state.with_legacy_preferences();
 
-        update_window_dark_state(state);
+        update_window_classes(&state);
+        let left_handed = state.left_handed;
+        Dispatch::<GameSize>::new().reduce_mut(|s| s.left_handed = left_handed);
         state
     }
 
@@ -65,18 +108,76 @@ impl Store for DarkModeState {
     }
 }
 
-struct DarkModeListener;
-impl Listener for DarkModeListener {
-    type Store = DarkModeState;
+impl VersionedStore for PreferencesState {
+    /// New settings only need a default. Bump this when a setting changes meaning.
+    const VERSION: u32 = 0;
+    const MIGRATIONS: &'static [Migration] = &[];
+}
+
+/// Dark mode, high contrast and the language used to be saved separately
+#[cfg(target_arch = "wasm32")]
+const LEGACY_KEYS: [&str; 3] = [
+    "myriad_yew::state::preferences_state::DarkModeState",
+    "myriad_yew::state::preferences_state::HighContrastState",
+    "myriad_yew::state::i18n::LanguageState",
+];
+
+#[cfg(target_arch = "wasm32")]
+impl PreferencesState {
+    /// Keep the theme chosen before there was a settings page, until the settings are first saved
+    fn with_legacy_preferences(mut self) -> Self {
+        let Some(storage) = window().and_then(|w| w.local_storage().ok().flatten()) else {
+            return self;
+        };
+        let [dark_mode_key, contrast_key, language_key] = LEGACY_KEYS;
+
+        if let Some(theme) = storage
+            .get_item(dark_mode_key)
+            .ok()
+            .flatten()
+            .and_then(|x| serde_json::from_str(&x).ok())
+        {
+            self.theme = theme;
+        }
+        if let Ok(Some(contrast)) = storage.get_item(contrast_key) {
+            self.high_contrast = contrast == "\"On\"";
+        }
+        if let Some(language) = storage
+            .get_item(language_key)
+            .ok()
+            .flatten()
+            .and_then(|x| serde_json::from_str::<serde_json::Value>(&x).ok())
+            .and_then(|x| serde_json::from_value(x.get("language")?.clone()).ok())
+        {
+            self.language = language;
+        }
+        self
+    }
+}
+
+struct PreferencesListener;
+impl Listener for PreferencesListener {
+    type Store = PreferencesState;
 
     fn on_change(&mut self, state: Rc<Self::Store>) {
-        update_window_dark_state(*state);
+        update_window_classes(&state);
+        let left_handed = state.left_handed;
+        Dispatch::<GameSize>::new().reduce_mut(|s| s.left_handed = left_handed);
+
+        // Like the timed mode, the current game changes mode if nothing has been found yet
+        let shortest_paths_only = state.shortest_paths_only;
+        Dispatch::<FullGameState>::new().apply(move |mut fs: Rc<FullGameState>| {
+            if fs.found_words.words.is_empty() && fs.shortest_paths_only != shortest_paths_only {
+                Rc::make_mut(&mut fs).shortest_paths_only = shortest_paths_only;
+            }
+            fs
+        });
 
-        log::info!("Saving dark mode state {state:?}");
         #[cfg(target_arch = "wasm32")]
-        {
-            use yewdux::storage::save;
-            save(state.as_ref(), storage::Area::Local).expect("unable to save state");
+        if let Some(storage) = window().and_then(|w| w.local_storage().ok().flatten()) {
+            for key in LEGACY_KEYS {
+                let _ = storage.remove_item(key);
+            }
         }
     }
 }
@@ -84,14 +185,157 @@ impl Listener for DarkModeListener {
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct DarkModeNextMessage;
 
-impl Reducer<DarkModeState> for DarkModeNextMessage {
-    fn apply(self, state: Rc<DarkModeState>) -> Rc<DarkModeState> {
+impl Reducer<PreferencesState> for DarkModeNextMessage {
+    fn apply(self, state: Rc<PreferencesState>) -> Rc<PreferencesState> {
         use DarkModeState::*;
-        match *state {
+        let theme = match state.theme {
             Auto => Light,
             Light => Dark,
             Dark => Auto,
+        };
+        PreferencesState {
+            theme,
+            ..state.as_ref().clone()
         }
         .into()
     }
 }
+
+/// Move to the next language, going back to the device language after the last one
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CycleLanguageMsg;
+
+impl Reducer<PreferencesState> for CycleLanguageMsg {
+    fn apply(self, state: Rc<PreferencesState>) -> Rc<PreferencesState> {
+        use strum::IntoEnumIterator;
+        let language = match state.language {
+            None => Language::iter().next(),
+            Some(language) => Language::iter().skip_while(|x| *x != language).nth(1),
+        };
+        PreferencesState {
+            language,
+            ..state.as_ref().clone()
+        }
+        .into()
+    }
+}
+
+/// A setting which is either on or off
+#[derive(Debug, Clone, Copy, PartialEq, Eq, EnumIter)]
+pub enum Preference {
+    HighContrast,
+    Haptics,
+    Confetti,
+    DifficultyDots,
+    ShowTimer,
+    LeftHanded,
+    ShortestPathsOnly,
+    Analytics,
+}
+
+impl Preference {
+    pub fn text(self) -> Text {
+        match self {
+            Preference::HighContrast => Text::HighContrast,
+            Preference::Haptics => Text::Haptics,
+            Preference::Confetti => Text::Confetti,
+            Preference::DifficultyDots => Text::DifficultyDots,
+            Preference::ShowTimer => Text::ShowTimer,
+            Preference::LeftHanded => Text::LeftHanded,
+            Preference::ShortestPathsOnly => Text::ShortestPathsOnly,
+            Preference::Analytics => Text::Analytics,
+        }
+    }
+
+    fn value_mut(self, state: &mut PreferencesState) -> &mut bool {
+        match self {
+            Preference::HighContrast => &mut state.high_contrast,
+            Preference::Haptics => &mut state.haptics,
+            Preference::Confetti => &mut state.confetti,
+            Preference::DifficultyDots => &mut state.difficulty_dots,
+            Preference::ShowTimer => &mut state.show_timer,
+            Preference::LeftHanded => &mut state.left_handed,
+            Preference::ShortestPathsOnly => &mut state.shortest_paths_only,
+            Preference::Analytics => &mut state.analytics_opt_out,
+        }
+    }
+
+    pub fn is_enabled(self, state: &PreferencesState) -> bool {
+        match self {
+            Preference::HighContrast => state.high_contrast,
+            Preference::Haptics => state.haptics,
+            Preference::Confetti => state.confetti,
+            Preference::DifficultyDots => state.difficulty_dots,
+            Preference::ShowTimer => state.show_timer,
+            Preference::LeftHanded => state.left_handed,
+            Preference::ShortestPathsOnly => state.shortest_paths_only,
+            // Analytics are stored as an opt out
+            Preference::Analytics => !state.analytics_opt_out,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TogglePreferenceMsg(pub Preference);
+
+impl Reducer<PreferencesState> for TogglePreferenceMsg {
+    fn apply(self, state: Rc<PreferencesState>) -> Rc<PreferencesState> {
+        let mut state = state.as_ref().clone();
+        let value = self.0.value_mut(&mut state);
+        *value = !*value;
+        state.into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use strum::IntoEnumIterator;
+
+    #[test]
+    fn test_toggle_preferences() {
+        for preference in Preference::iter() {
+            let state: Rc<PreferencesState> = Default::default();
+            let toggled = TogglePreferenceMsg(preference).apply(state.clone());
+
+            assert_ne!(
+                preference.is_enabled(&state),
+                preference.is_enabled(&toggled)
+            );
+            assert_eq!(
+                TogglePreferenceMsg(preference).apply(toggled).as_ref(),
+                state.as_ref()
+            );
+        }
+
+        let state: Rc<PreferencesState> = Default::default();
+        let toggled = TogglePreferenceMsg(Preference::Analytics).apply(state);
+        assert!(toggled.analytics_opt_out);
+    }
+
+    #[test]
+    fn test_missing_preferences_use_defaults() {
+        let state =
+            PreferencesState::from_json(r#"{"version":0,"data":{"theme":"Dark","haptics":false}}"#)
+                .unwrap();
+
+        assert_eq!(state.theme, DarkModeState::Dark);
+        assert!(!state.haptics);
+        assert!(state.confetti);
+        assert!(state.show_timer);
+        assert!(!state.analytics_opt_out);
+        assert_eq!(state.language, None);
+    }
+
+    #[test]
+    fn test_cycle_language() {
+        let mut state: Rc<PreferencesState> = Default::default();
+        let mut seen = vec![];
+        for _ in 0..=Language::iter().count() {
+            state = CycleLanguageMsg.apply(state);
+            seen.push(state.language);
+        }
+        assert_eq!(seen.first(), Some(&Some(Language::English)));
+        assert_eq!(seen.last(), Some(&None));
+    }
+}
@@ -0,0 +1,290 @@
+use crate::state::info_bar_state::{InfoBarSetMessage, InfoBarState};
+use crate::state::prelude::*;
+use myriad::prelude::*;
+use myriad::race::*;
+use serde::{Deserialize, Serialize};
+use std::cell::RefCell;
+use std::collections::BTreeMap;
+use std::rc::Rc;
+use wasm_bindgen::{prelude::Closure, JsCast};
+use yewdux::prelude::*;
+
+/// The race server to connect to, set with `MYRIAD_RACE_SERVER` when building.
+/// Racing is unavailable in builds without one.
+pub const RACE_SERVER_URL: Option<&str> = option_env!("MYRIAD_RACE_SERVER");
+
+thread_local! {
+    static SOCKET: RefCell<Option<web_sys::WebSocket>> = RefCell::new(None);
+}
+
+/// The name other racers see
+#[derive(PartialEq, Eq, Clone, Serialize, Deserialize, Store)]
+#[store(storage = "local")]
+pub struct RacePlayerState {
+    pub name: String,
+}
+
+impl Default for RacePlayerState {
+    fn default() -> Self {
+        let number: u16 = rand::Rng::gen_range(&mut rand::thread_rng(), 100..1000);
+        Self {
+            name: format!("Player {number}"),
+        }
+    }
+}
+
+/// The race room this player is in
+#[derive(PartialEq, Eq, Clone, Default, Store)]
+pub struct RaceState {
+    pub board: Option<Board<GRID_COLUMNS, GRID_ROWS, GRID_SIZE>>,
+    pub connected: bool,
+    /// The name the race server gave this player
+    pub name: String,
+    pub players: Vec<String>,
+    /// The player who found each number first
+    pub first_finds: BTreeMap<i32, String>,
+    /// Paths found before the server let this player in, which are sent once it does
+    pub pending: Vec<Vec<u8>>,
+}
+
+impl RaceState {
+    /// The first finds for this board, if racing on it
+    pub fn for_board(
+        &self,
+        board: &Board<GRID_COLUMNS, GRID_ROWS, GRID_SIZE>,
+    ) -> Option<&BTreeMap<i32, String>> {
+        (self.board.as_ref() == Some(board)).then_some(&self.first_finds)
+    }
+
+    pub fn after_message(&self, message: &ServerMessage) -> Self {
+        let mut state = self.clone();
+        match message {
+            ServerMessage::Joined {
+                name,
+                players,
+                found,
+                ..
+            } => {
+                state.connected = true;
+                state.name = name.clone();
+                state.players = players.clone();
+                state.first_finds = found.iter().map(|x| (x.number, x.name.clone())).collect();
+            }
+            ServerMessage::PlayerJoined { name } => state.players.push(name.clone()),
+            ServerMessage::PlayerLeft { name } => {
+                if let Some(index) = state.players.iter().position(|x| x == name) {
+                    state.players.remove(index);
+                }
+            }
+            ServerMessage::Found(find) => {
+                state
+                    .first_finds
+                    .entry(find.number)
+                    .or_insert_with(|| find.name.clone());
+            }
+            ServerMessage::Rejected { .. } => {}
+        }
+        state
+    }
+}
+
+fn send(message: &ClientMessage) {
+    let Ok(text) = serde_json::to_string(message) else {
+        return;
+    };
+    SOCKET.with(|socket| {
+        if let Some(socket) = socket.borrow().as_ref() {
+            if socket.ready_state() == web_sys::WebSocket::OPEN {
+                if let Err(err) = socket.send_with_str(&text) {
+                    log::error!("Could not send race message: {err:?}");
+                }
+            }
+        }
+    });
+}
+
+/// Join the race room for a board
+pub struct JoinRaceMsg {
+    pub board: Board<GRID_COLUMNS, GRID_ROWS, GRID_SIZE>,
+}
+
+impl Reducer<RaceState> for JoinRaceMsg {
+    fn apply(self, state: Rc<RaceState>) -> Rc<RaceState> {
+        if state.board.as_ref() == Some(&self.board) {
+            return state;
+        }
+
+        let Some(url) = RACE_SERVER_URL else {
+            let text = current_language()
+                .translate(Text::RaceUnavailable)
+                .to_string();
+            Dispatch::<InfoBarState>::new().apply(InfoBarSetMessage(InfoBarState::Challenge(text)));
+            return state;
+        };
+        let socket = match web_sys::WebSocket::new(url) {
+            Ok(socket) => socket,
+            Err(err) => {
+                log::error!("Could not connect to race server: {err:?}");
+                return state;
+            }
+        };
+
+        let join = ClientMessage::Join {
+            board: self.board.canonical_string(),
+            name: Dispatch::<RacePlayerState>::new().get().name.clone(),
+        };
+        let on_open = Closure::<dyn Fn()>::new(move || send(&join));
+        socket.set_onopen(Some(on_open.as_ref().unchecked_ref()));
+        on_open.forget();
+
+        // Messages and closes from the socket for a room which has since been left are ignored
+        let board = self.board.clone();
+        let on_message =
+            Closure::<dyn Fn(web_sys::MessageEvent)>::new(move |event: web_sys::MessageEvent| {
+                if !is_current_room(&board) {
+                    return;
+                }
+                let Some(text) = event.data().as_string() else {
+                    return;
+                };
+                match serde_json::from_str::<ServerMessage>(&text) {
+                    Ok(message) => Dispatch::<RaceState>::new().apply(ServerMessageMsg(message)),
+                    Err(err) => log::error!("Could not read race message: {err}"),
+                }
+            });
+        socket.set_onmessage(Some(on_message.as_ref().unchecked_ref()));
+        on_message.forget();
+
+        let board = self.board.clone();
+        let on_close = Closure::<dyn Fn()>::new(move || {
+            if is_current_room(&board) {
+                Dispatch::<RaceState>::new().reduce_mut(|s| s.connected = false)
+            }
+        });
+        socket.set_onclose(Some(on_close.as_ref().unchecked_ref()));
+        on_close.forget();
+
+        SOCKET.with(|x| {
+            if let Some(previous) = x.replace(Some(socket)) {
+                let _ = previous.close();
+            }
+        });
+
+        RaceState {
+            board: Some(self.board),
+            ..Default::default()
+        }
+        .into()
+    }
+}
+
+fn is_current_room(board: &Board<GRID_COLUMNS, GRID_ROWS, GRID_SIZE>) -> bool {
+    Dispatch::<RaceState>::new().get().board.as_ref() == Some(board)
+}
+
+/// A message from the race server
+pub struct ServerMessageMsg(pub ServerMessage);
+
+impl Reducer<RaceState> for ServerMessageMsg {
+    fn apply(self, state: Rc<RaceState>) -> Rc<RaceState> {
+        let name = state.name.clone();
+        let announcement = match &self.0 {
+            ServerMessage::PlayerJoined { name } => {
+                Some(current_language().translate_with(Text::JoinedRace, &[name]))
+            }
+            ServerMessage::Found(find)
+                if find.name != name && !state.first_finds.contains_key(&find.number) =>
+            {
+                Some(
+                    current_language()
+                        .translate_with(Text::FoundFirst, &[&find.name, &find.number]),
+                )
+            }
+            ServerMessage::Rejected { reason } => {
+                log::warn!("Race server rejected message: {reason}");
+                None
+            }
+            _ => None,
+        };
+        if let Some(text) = announcement {
+            Dispatch::<InfoBarState>::new().apply(InfoBarSetMessage(InfoBarState::Challenge(text)));
+        }
+
+        let mut state = state.after_message(&self.0);
+        if state.connected {
+            for path in std::mem::take(&mut state.pending) {
+                send(&ClientMessage::Submit { path });
+            }
+        }
+        state.into()
+    }
+}
+
+/// Send a newly found number to the race server if racing on this board.
+/// It is sent after joining if the server has not let this player in yet.
+pub fn submit_race_path(
+    board: &Board<GRID_COLUMNS, GRID_ROWS, GRID_SIZE>,
+    path: &[Tile<GRID_COLUMNS, GRID_ROWS>],
+) {
+    let dispatch = Dispatch::<RaceState>::new();
+    let state = dispatch.get();
+    if state.board.as_ref() != Some(board) {
+        return;
+    }
+
+    let path = path.iter().map(|x| x.inner()).collect();
+    if state.connected {
+        send(&ClientMessage::Submit { path });
+    } else {
+        dispatch.reduce_mut(|s| s.pending.push(path));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn find(number: i32, name: &str) -> FirstFind {
+        FirstFind {
+            number,
+            name: name.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_server_messages() {
+        let board = Board::try_create("1+536-249").unwrap();
+        let state = RaceState {
+            board: Some(board.clone()),
+            ..Default::default()
+        };
+
+        let state = state.after_message(&ServerMessage::Joined {
+            board: board.canonical_string(),
+            name: "Bob".to_string(),
+            players: vec!["Alice".to_string(), "Bob".to_string()],
+            found: vec![find(7, "Alice")],
+        });
+        assert!(state.connected);
+        assert_eq!(state.name, "Bob");
+        assert_eq!(state.first_finds.get(&7), Some(&"Alice".to_string()));
+
+        let state = state
+            .after_message(&ServerMessage::PlayerJoined {
+                name: "Carol".to_string(),
+            })
+            .after_message(&ServerMessage::PlayerLeft {
+                name: "Alice".to_string(),
+            })
+            .after_message(&ServerMessage::Found(find(8, "Carol")))
+            .after_message(&ServerMessage::Found(find(7, "Carol")));
+
+        assert_eq!(state.players, vec!["Bob".to_string(), "Carol".to_string()]);
+        assert_eq!(state.first_finds.get(&7), Some(&"Alice".to_string()));
+        assert_eq!(state.first_finds.get(&8), Some(&"Carol".to_string()));
+
+        let other = Board::try_create("1+536-248").unwrap();
+        assert!(state.for_board(&board).is_some());
+        assert!(state.for_board(&other).is_none());
+    }
+}
@@ -12,6 +12,8 @@ pub struct WordFoundMsg {
 
 impl Reducer<RecentWordState> for WordFoundMsg {
     fn apply(self, state: std::rc::Rc<RecentWordState>) -> std::rc::Rc<RecentWordState> {
+        let announcement = self.word_type.announcement(self.word, current_language());
+        Dispatch::<AnnouncementState>::new().apply(AnnounceMsg(announcement));
         state
             .with_word(self.word, self.word_type, self.coordinate)
             .into()
@@ -46,7 +48,7 @@ impl RecentWordState {
         word_type: FoundWordType,
         coordinate: Tile<GRID_COLUMNS, GRID_ROWS>,
     ) -> Self {
-        if word_type != FoundWordType::Found {
+        if !word_type.is_shown() {
             return self.clone();
         }
 
@@ -93,9 +95,16 @@ pub enum FoundWordType {
     Found,
     PreviouslyFound,
     NotInRange,
+    /// A new number found with a longer path than necessary, in a game which only counts the shortest paths
+    NotShortest,
 }
 
 impl FoundWordType {
+    /// Whether the number flies across to its place in the found words
+    pub fn is_shown(&self) -> bool {
+        matches!(self, FoundWordType::Found | FoundWordType::NotShortest)
+    }
+
     pub fn linger_duration_ms(&self) -> u64 {
         const BASIS: u64 = 1000;
 
@@ -103,6 +112,7 @@ impl FoundWordType {
             FoundWordType::Found => BASIS * 10,
             FoundWordType::PreviouslyFound => BASIS * 5,
             FoundWordType::NotInRange => BASIS * 4,
+            FoundWordType::NotShortest => BASIS * 4,
         }
     }
 }
@@ -117,6 +127,7 @@ impl RecentWord {
             FoundWordType::Found => "var(--recent-word-found)".to_string(),
             FoundWordType::PreviouslyFound => "var(--recent-word-previous)".to_string(),
             FoundWordType::NotInRange => "var(--recent-word-invalid)".to_string(),
+            FoundWordType::NotShortest => "var(--recent-word-not-shortest)".to_string(),
         }
     }
 }
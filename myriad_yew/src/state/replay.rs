@@ -0,0 +1,129 @@
+use crate::state::prelude::*;
+use myriad::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// A number which was found during a game and when it was found
+#[derive(PartialEq, Eq, Clone, Serialize, Deserialize, Debug)]
+pub struct FoundWordEvent {
+    pub word: FoundWord<GRID_COLUMNS, GRID_ROWS, GRID_SIZE>,
+    pub utc_time_milliseconds: i64,
+}
+
+/// One found word in a replay
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub struct ReplayStep {
+    pub word: FoundWord<GRID_COLUMNS, GRID_ROWS, GRID_SIZE>,
+    /// Milliseconds since the first word was found
+    pub elapsed_milliseconds: u64,
+    /// When this step is shown during playback
+    pub playback_milliseconds: u64,
+}
+
+impl ReplayStep {
+    pub fn time_string(&self) -> Option<String> {
+        GameTiming::Finished {
+            total_milliseconds: self.elapsed_milliseconds,
+        }
+        .time_string()
+    }
+}
+
+/// The found words of a game, spaced out for playback.
+/// Long pauses are shortened so the replay keeps moving.
+#[derive(PartialEq, Eq, Clone, Debug, Default)]
+pub struct Replay {
+    pub steps: Vec<ReplayStep>,
+}
+
+impl Replay {
+    pub const MIN_GAP_MILLISECONDS: u64 = 250;
+    pub const MAX_GAP_MILLISECONDS: u64 = 2000;
+
+    pub fn new(events: &[FoundWordEvent]) -> Self {
+        let Some(first) = events.first() else {
+            return Self::default();
+        };
+
+        let mut previous = first.utc_time_milliseconds;
+        let mut playback_milliseconds = 0;
+
+        let steps = events
+            .iter()
+            .map(|event| {
+                let gap = event.utc_time_milliseconds.saturating_sub(previous).max(0) as u64;
+                playback_milliseconds +=
+                    gap.clamp(Self::MIN_GAP_MILLISECONDS, Self::MAX_GAP_MILLISECONDS);
+                previous = event.utc_time_milliseconds;
+
+                ReplayStep {
+                    word: event.word.clone(),
+                    elapsed_milliseconds: event
+                        .utc_time_milliseconds
+                        .saturating_sub(first.utc_time_milliseconds)
+                        .max(0) as u64,
+                    playback_milliseconds,
+                }
+            })
+            .collect();
+
+        Self { steps }
+    }
+
+    /// How long the whole replay takes to play
+    pub fn duration_milliseconds(&self) -> u64 {
+        self.steps
+            .last()
+            .map(|x| x.playback_milliseconds)
+            .unwrap_or_default()
+    }
+
+    /// The number of steps which have been shown at this point in the playback
+    pub fn steps_shown(&self, playback_milliseconds: u64) -> usize {
+        self.steps
+            .partition_point(|x| x.playback_milliseconds <= playback_milliseconds)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::versioned_storage::VersionedStore;
+
+    #[test]
+    fn test_replay() {
+        let state =
+            FullGameState::from_json(include_str!("../../fixtures/full_game_state_v4.json"))
+                .unwrap();
+        let replay = Replay::new(&state.events);
+
+        let elapsed = replay
+            .steps
+            .iter()
+            .map(|x| x.elapsed_milliseconds)
+            .collect::<Vec<_>>();
+        assert_eq!(elapsed, vec![0, 100, 5_100, 65_100]);
+
+        let playback = replay
+            .steps
+            .iter()
+            .map(|x| x.playback_milliseconds)
+            .collect::<Vec<_>>();
+        assert_eq!(playback, vec![250, 500, 2_500, 4_500]);
+        assert_eq!(replay.duration_milliseconds(), 4_500);
+
+        assert_eq!(replay.steps_shown(0), 0);
+        assert_eq!(replay.steps_shown(250), 1);
+        assert_eq!(replay.steps_shown(2_499), 2);
+        assert_eq!(replay.steps_shown(10_000), 4);
+
+        assert_eq!(replay.steps[3].word.result, 10);
+        assert_eq!(replay.steps[3].time_string(), Some("01:05".to_string()));
+    }
+
+    #[test]
+    fn test_empty_replay() {
+        let replay = Replay::new(&[]);
+        assert_eq!(replay.duration_milliseconds(), 0);
+        assert_eq!(replay.steps_shown(1000), 0);
+    }
+}
@@ -33,8 +33,9 @@ impl RotFlipState {
     ) -> Location {
         let flipped = rotate_and_flip(point, self.rotate, self.flip);
 
-        let x =
-            ((flipped.x() as f32) * game_size.square_length()) + (game_size.square_radius() * 0.9);
+        let x = game_size.board_origin_x()
+            + ((flipped.x() as f32) * game_size.square_length())
+            + (game_size.square_radius() * 0.9);
         let y = ((flipped.y() as f32) * game_size.square_length()) + game_size.square_radius();
 
         Location { x, y }
@@ -0,0 +1,114 @@
+use crate::state::game_rating::GameRating;
+use crate::state::prelude::*;
+use myriad::prelude::Difficulty;
+
+/// The emoji for a number in the results grid
+fn grid_square(difficulty: Option<&Difficulty>, found: bool) -> char {
+    match (difficulty, found) {
+        (None, _) => '⬛',
+        (Some(_), false) => '⬜',
+        (Some(difficulty), true) => match difficulty.0.get() {
+            1..=3 => '🟩',
+            4..=6 => '🟨',
+            _ => '🟥',
+        },
+    }
+}
+
+/// A result card which can be shared without giving away any solutions
+pub fn share_text(state: &FullGameState, rating: &GameRating, language: Language) -> String {
+    let mut lines: Vec<String> = vec![];
+
+    match state.game.date {
+        Some(date) => lines.push(format!("Myriad {date}")),
+        None => lines.push(format!("Myriad {}", state.game.board.to_single_string())),
+    }
+
+    let (found, total) = state.get_found_count();
+    let mut summary = format!("{found}/{total}");
+    if let Some(time_string) = state.timing.time_string() {
+        summary.push_str(&format!(" ⏱️{time_string}"));
+    }
+    if state.timing == GameTiming::Cheat {
+        summary.push_str(" 😈");
+    }
+    lines.push(summary);
+
+    let mut scores = format!(
+        "🎯{}",
+        language.format_percent(rating.efficiency_percent() as i64)
+    );
+    let hints = state.hints.total_hinted();
+    if hints > 0 {
+        scores.push_str(&format!(" 💡{hints}"));
+    }
+    lines.push(scores);
+
+    for row in 0..10 {
+        let line: String = (1..=10)
+            .map(|column| {
+                let number = (row * 10) + column;
+                let difficulty = state
+                    .game
+                    .difficulties
+                    .get((number - 1) as usize)
+                    .and_then(|x| x.as_ref());
+                grid_square(difficulty, state.found_words.words.contains_key(&number))
+            })
+            .collect();
+        lines.push(line);
+    }
+
+    lines.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::versioned_storage::VersionedStore;
+
+    fn history() -> HistoryState {
+        HistoryState::from_json(include_str!("../../fixtures/history_state_v1.json")).unwrap()
+    }
+
+    #[test]
+    fn test_share_finished_game() {
+        let state = &history().games[0];
+        let rating = GameRating::create(state);
+        let text = share_text(state, &rating, Language::English);
+        let lines: Vec<&str> = text.lines().collect();
+
+        assert_eq!(lines[0], "Myriad 2023-06-01");
+        assert_eq!(lines[1], "100/100 ⏱️12:34");
+        assert_eq!(lines[2], format!("🎯{}%", rating.efficiency_percent()));
+        assert_eq!(lines.len(), 13);
+
+        let french = share_text(state, &rating, Language::French);
+        assert!(french.contains("\u{00A0}%"));
+
+        for line in &lines[3..] {
+            assert_eq!(line.chars().count(), 10);
+            assert!(!line.contains('⬜'));
+        }
+    }
+
+    #[test]
+    fn test_share_partial_game() {
+        let state = &history().games[1];
+        let rating = GameRating::create(state);
+        let text = share_text(state, &rating, Language::English);
+        let lines: Vec<&str> = text.lines().collect();
+
+        assert_eq!(lines[0], "Myriad ⅠⅠⅤ-Ⅹ+ⅬⅭⅩ");
+        assert_eq!(lines[1], format!("2/{} 😈", state.game.total_solutions));
+        assert!(lines[2].ends_with("💡1"));
+
+        let grid: String = lines[3..].concat();
+        let coloured = grid.chars().filter(|c| !matches!(c, '⬛' | '⬜')).count();
+        assert_eq!(coloured, 2);
+        assert_eq!(
+            grid.chars().filter(|c| *c == '⬛').count(),
+            100 - state.game.total_solutions
+        );
+    }
+}
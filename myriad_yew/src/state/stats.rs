@@ -0,0 +1,295 @@
+use crate::state::prelude::*;
+use chrono::NaiveDate;
+use itertools::Itertools;
+use std::collections::BTreeMap;
+
+/// How many of the numbers of one difficulty were found, across all games
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct FindRate {
+    pub found: usize,
+    pub total: usize,
+}
+
+impl FindRate {
+    pub fn percent(&self) -> usize {
+        if self.total == 0 {
+            0
+        } else {
+            self.found * 100 / self.total
+        }
+    }
+}
+
+/// Consecutive days on which daily games were played
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Streaks {
+    /// The streak ending today, or yesterday if today's game has not been played yet
+    pub current: usize,
+    pub longest: usize,
+}
+
+impl Streaks {
+    pub fn create(dates: impl Iterator<Item = NaiveDate>, today: NaiveDate) -> Self {
+        let dates = dates.sorted().dedup().collect_vec();
+
+        let mut longest = 0;
+        let mut run = 0;
+        let mut previous: Option<NaiveDate> = None;
+
+        for date in dates.iter() {
+            run = match previous {
+                Some(previous) if previous.succ_opt() == Some(*date) => run + 1,
+                _ => 1,
+            };
+            longest = longest.max(run);
+            previous = Some(*date);
+        }
+
+        let current = match dates.last() {
+            Some(last) if *last == today || last.succ_opt() == Some(today) => run,
+            _ => 0,
+        };
+
+        Self { current, longest }
+    }
+
+    /// Streaks from the days daily games were actually played, whichever days the games were for
+    pub fn for_games<'a>(games: impl Iterator<Item = &'a FullGameState>, today: NaiveDate) -> Self {
+        let dates = games
+            .filter(|x| x.game.date.is_some())
+            .filter_map(|x| x.played);
+        Self::create(dates, today)
+    }
+}
+
+/// Statistics across every game in the history
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct GameStats {
+    pub games_played: usize,
+    pub games_completed: usize,
+    pub streaks: Streaks,
+    pub average_milliseconds: Option<u64>,
+    pub best_milliseconds: Option<u64>,
+    /// Numbers which were possible but not found, with the number of games they were missed in, most missed first
+    pub most_missed: Vec<(i32, usize)>,
+    /// Find rates keyed by the length of the shortest solution
+    pub find_rates: BTreeMap<u8, FindRate>,
+}
+
+impl GameStats {
+    pub const MOST_MISSED_COUNT: usize = 10;
+
+    pub fn create<'a>(games: impl Iterator<Item = &'a FullGameState>, today: NaiveDate) -> Self {
+        let games = games
+            .filter(|x| !x.found_words.words.is_empty())
+            .collect_vec();
+
+        let games_played = games.len();
+        let games_completed = games
+            .iter()
+            .filter(|x| {
+                let (found, total) = x.get_found_count();
+                found >= total
+            })
+            .count();
+
+        let streaks = Streaks::for_games(games.iter().copied(), today);
+
+        let times = games
+            .iter()
+            .filter_map(|x| match x.timing {
+                GameTiming::Finished { total_milliseconds } => Some(total_milliseconds),
+                _ => None,
+            })
+            .collect_vec();
+        let best_milliseconds = times.iter().min().copied();
+        let average_milliseconds = match times.len() {
+            0 => None,
+            n => Some(times.iter().sum::<u64>() / n as u64),
+        };
+
+        let mut missed: BTreeMap<i32, usize> = Default::default();
+        let mut find_rates: BTreeMap<u8, FindRate> = Default::default();
+
+        for state in games.iter() {
+            for (index, difficulty) in state.game.difficulties.iter().enumerate() {
+                let Some(difficulty) = difficulty else {
+                    continue;
+                };
+                let number = index as i32 + 1;
+                let found = state.found_words.words.contains_key(&number);
+
+                let rate = find_rates.entry(difficulty.0.get()).or_default();
+                rate.total += 1;
+                if found {
+                    rate.found += 1;
+                } else {
+                    *missed.entry(number).or_default() += 1;
+                }
+            }
+        }
+
+        let most_missed = missed
+            .into_iter()
+            .sorted_by_key(|(number, count)| (std::cmp::Reverse(*count), *number))
+            .take(Self::MOST_MISSED_COUNT)
+            .collect_vec();
+
+        Self {
+            games_played,
+            games_completed,
+            streaks,
+            average_milliseconds,
+            best_milliseconds,
+            most_missed,
+            find_rates,
+        }
+    }
+
+    pub fn completion_percent(&self) -> usize {
+        if self.games_played == 0 {
+            0
+        } else {
+            self.games_completed * 100 / self.games_played
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::versioned_storage::VersionedStore;
+
+    fn date(month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2023, month, day).unwrap()
+    }
+
+    #[test]
+    fn test_streaks() {
+        let dates = [date(6, 1), date(6, 2), date(6, 3), date(6, 5), date(6, 6)];
+
+        assert_eq!(
+            Streaks::create(dates.into_iter(), date(6, 6)),
+            Streaks {
+                current: 2,
+                longest: 3
+            }
+        );
+        assert_eq!(
+            Streaks::create(dates.into_iter(), date(6, 7)),
+            Streaks {
+                current: 2,
+                longest: 3
+            }
+        );
+        assert_eq!(
+            Streaks::create(dates.into_iter(), date(6, 8)),
+            Streaks {
+                current: 0,
+                longest: 3
+            }
+        );
+    }
+
+    #[test]
+    fn test_streaks_ignore_duplicates_and_order() {
+        let dates = [date(6, 30), date(7, 1), date(6, 30), date(6, 29)];
+
+        assert_eq!(
+            Streaks::create(dates.into_iter(), date(7, 1)),
+            Streaks {
+                current: 3,
+                longest: 3
+            }
+        );
+        assert_eq!(
+            Streaks::create(std::iter::empty(), date(7, 1)),
+            Streaks::default()
+        );
+    }
+
+    #[test]
+    fn test_game_stats() {
+        let history =
+            HistoryState::from_json(include_str!("../../fixtures/history_state_v1.json")).unwrap();
+
+        let mut first_day = history.games[0].clone();
+        first_day.played = Some(date(6, 1));
+
+        let mut second_day = history.games[0].clone();
+        second_day.game.date = Some(date(6, 2));
+        second_day.played = Some(date(6, 2));
+        second_day.timing = GameTiming::Finished {
+            total_milliseconds: 245679,
+        };
+
+        let games = [first_day, second_day, history.games[1].clone()];
+        let stats = GameStats::create(games.iter(), date(6, 3));
+
+        assert_eq!(stats.games_played, 3);
+        assert_eq!(stats.games_completed, 2);
+        assert_eq!(stats.completion_percent(), 66);
+        assert_eq!(
+            stats.streaks,
+            Streaks {
+                current: 2,
+                longest: 2
+            }
+        );
+        assert_eq!(stats.best_milliseconds, Some(245679));
+        assert_eq!(stats.average_milliseconds, Some(500000));
+
+        let cheat_game = &history.games[1];
+        assert_eq!(
+            stats.most_missed.len(),
+            GameStats::MOST_MISSED_COUNT.min(cheat_game.game.total_solutions - 2)
+        );
+        assert!(stats.most_missed.iter().all(
+            |(number, count)| *count == 1 && !cheat_game.found_words.words.contains_key(number)
+        ));
+
+        let total: usize = stats.find_rates.values().map(|x| x.total).sum();
+        let found: usize = stats.find_rates.values().map(|x| x.found).sum();
+        assert_eq!(total, 200 + cheat_game.game.total_solutions);
+        assert_eq!(found, 202);
+    }
+
+    #[test]
+    fn test_old_puzzles_count_for_the_day_they_are_played() {
+        let history =
+            HistoryState::from_json(include_str!("../../fixtures/history_state_v1.json")).unwrap();
+        let mut yesterday = history.games[0].clone();
+        yesterday.game.date = Some(date(6, 2));
+        yesterday.played = Some(date(6, 2));
+
+        let old_puzzles = (3..=5)
+            .map(|day| {
+                let mut game = history.games[0].clone();
+                game.game.date = Some(date(5, day));
+                game.played = Some(date(6, 3));
+                game
+            })
+            .collect_vec();
+        let games = std::iter::once(&yesterday).chain(old_puzzles.iter());
+
+        assert_eq!(
+            GameStats::create(games, date(6, 3)).streaks,
+            Streaks {
+                current: 2,
+                longest: 2
+            }
+        );
+    }
+
+    #[test]
+    fn test_unplayed_games_are_ignored() {
+        let history =
+            HistoryState::from_json(include_str!("../../fixtures/history_state_v1.json")).unwrap();
+        let mut unplayed = history.games[0].clone();
+        unplayed.found_words = Default::default();
+
+        let stats = GameStats::create([unplayed].iter(), date(6, 1));
+
+        assert_eq!(stats, GameStats::default());
+    }
+}
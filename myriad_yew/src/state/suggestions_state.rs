@@ -0,0 +1,127 @@
+use crate::state::prelude::*;
+use myriad::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::rc::Rc;
+use yewdux::prelude::*;
+
+/// Which tiles can continue the current path to make numbers which have not yet been found
+#[derive(PartialEq, Eq, Clone, Default, Serialize, Deserialize, Store)]
+#[store(storage = "local")]
+pub struct SuggestionsState {
+    pub enabled: bool,
+    #[serde(skip)]
+    pub continuations: Vec<Continuation<GRID_COLUMNS, GRID_ROWS>>,
+    /// Every path on the board last solved, so the board is only solved once however the path changes
+    #[serde(skip)]
+    pub paths: Option<SolvedPaths>,
+}
+
+#[derive(Eq, Clone)]
+pub struct SolvedPaths {
+    pub board: Board<GRID_COLUMNS, GRID_ROWS, GRID_SIZE>,
+    pub paths: Rc<Vec<FoundWord<GRID_COLUMNS, GRID_ROWS, GRID_SIZE>>>,
+}
+
+/// Paths are only solved once per board, so the lists are compared by pointer rather than by every path
+impl PartialEq for SolvedPaths {
+    fn eq(&self, other: &Self) -> bool {
+        self.board == other.board && Rc::ptr_eq(&self.paths, &other.paths)
+    }
+}
+
+impl SolvedPaths {
+    pub fn new(game: &Game) -> Self {
+        Self {
+            board: game.board.clone(),
+            paths: Rc::new(
+                game.solve_settings
+                    .solve_all_paths(game.board.clone())
+                    .collect(),
+            ),
+        }
+    }
+}
+
+impl SuggestionsState {
+    pub fn get_continuation(
+        &self,
+        tile: &Tile<GRID_COLUMNS, GRID_ROWS>,
+    ) -> Option<&Continuation<GRID_COLUMNS, GRID_ROWS>> {
+        self.continuations.iter().find(|x| x.tile == *tile)
+    }
+
+    pub fn is_promising(&self, tile: &Tile<GRID_COLUMNS, GRID_ROWS>) -> bool {
+        self.get_continuation(tile).is_some()
+    }
+
+    fn calculate_continuations(
+        fs: &FullGameState,
+        paths: &SolvedPaths,
+    ) -> Vec<Continuation<GRID_COLUMNS, GRID_ROWS>> {
+        let positions = Dispatch::<ChosenPositionsState>::new().get().positions;
+
+        Continuation::from_paths(paths.paths.iter(), &positions)
+            .into_iter()
+            .filter_map(|mut continuation| {
+                continuation
+                    .results
+                    .retain(|number| !fs.found_words.words.contains_key(number));
+
+                (!continuation.results.is_empty()).then_some(continuation)
+            })
+            .collect()
+    }
+}
+
+pub struct ToggleSuggestionsMsg;
+
+impl Reducer<SuggestionsState> for ToggleSuggestionsMsg {
+    fn apply(self, state: Rc<SuggestionsState>) -> Rc<SuggestionsState> {
+        UpdateSuggestionsMsg.apply(
+            SuggestionsState {
+                enabled: !state.enabled,
+                continuations: vec![],
+                paths: state.paths.clone(),
+            }
+            .into(),
+        )
+    }
+}
+
+/// Recalculate suggestions after the board, the chosen positions, or the found words change
+pub struct UpdateSuggestionsMsg;
+
+impl Reducer<SuggestionsState> for UpdateSuggestionsMsg {
+    fn apply(self, state: Rc<SuggestionsState>) -> Rc<SuggestionsState> {
+        //There are no suggestions for tutorial boards
+        if !state.enabled || Dispatch::<TutorialState>::new().get().is_active() {
+            if state.continuations.is_empty() {
+                return state;
+            }
+            return SuggestionsState {
+                enabled: state.enabled,
+                continuations: vec![],
+                paths: state.paths.clone(),
+            }
+            .into();
+        }
+
+        let fs = Dispatch::<FullGameState>::new().get();
+        let paths = match &state.paths {
+            Some(paths) if paths.board == fs.game.board => paths.clone(),
+            _ => SolvedPaths::new(&fs.game),
+        };
+        let continuations = SuggestionsState::calculate_continuations(&fs, &paths);
+
+        if continuations == state.continuations && state.paths.as_ref() == Some(&paths) {
+            return state;
+        }
+
+        SuggestionsState {
+            enabled: state.enabled,
+            continuations,
+            paths: Some(paths),
+        }
+        .into()
+    }
+}
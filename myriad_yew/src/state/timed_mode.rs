@@ -0,0 +1,235 @@
+use crate::state::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::rc::Rc;
+use yewdux::prelude::*;
+
+/// The length of a countdown game
+pub const COUNTDOWN_MILLISECONDS: u64 = 5 * 60 * 1000;
+
+/// Splits are recorded each time this many numbers have been found
+pub const SPLIT_INTERVAL: usize = 10;
+
+#[derive(PartialEq, Eq, Clone, Copy, Serialize, Deserialize, Default, Debug)]
+pub enum TimedMode {
+    /// No time pressure
+    #[default]
+    Relaxed,
+    /// Record a split time every ten numbers
+    TimeAttack,
+    /// Find as many numbers as possible in five minutes
+    Countdown,
+}
+
+impl TimedMode {
+    pub fn next(&self) -> Self {
+        match self {
+            TimedMode::Relaxed => TimedMode::TimeAttack,
+            TimedMode::TimeAttack => TimedMode::Countdown,
+            TimedMode::Countdown => TimedMode::Relaxed,
+        }
+    }
+
+    pub fn icon(&self) -> &'static str {
+        match self {
+            TimedMode::Relaxed => "∞",
+            TimedMode::TimeAttack => "⏱",
+            TimedMode::Countdown => "⏳",
+        }
+    }
+}
+
+/// The time taken to find a number of words
+#[derive(PartialEq, Eq, Clone, Copy, Serialize, Deserialize, Debug)]
+pub struct Split {
+    pub found: usize,
+    pub milliseconds: u64,
+}
+
+/// The timed mode a game is being played in and the results for that mode
+#[derive(PartialEq, Eq, Clone, Serialize, Deserialize, Default, Debug)]
+pub struct TimedGame {
+    pub mode: TimedMode,
+    pub splits: Vec<Split>,
+    /// The number of words found when a countdown game ended
+    pub countdown_found: Option<usize>,
+    /// When the first move of a countdown game was made, which is when the countdown begins
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub countdown_started: Option<i64>,
+}
+
+impl TimedGame {
+    pub fn new(mode: TimedMode) -> Self {
+        Self {
+            mode,
+            ..Default::default()
+        }
+    }
+
+    /// Record a split if this is a time attack game and a multiple of ten words have been found
+    pub fn with_split(&self, found: usize, elapsed_milliseconds: u64) -> Self {
+        let mut result = self.clone();
+        if self.mode == TimedMode::TimeAttack
+            && found > 0
+            && found % SPLIT_INTERVAL == 0
+            && !self.splits.iter().any(|x| x.found == found)
+        {
+            result.splits.push(Split {
+                found,
+                milliseconds: elapsed_milliseconds,
+            });
+        }
+        result
+    }
+
+    /// Milliseconds left in a countdown game, or none if this is not a countdown game.
+    /// The whole countdown is left until the first move.
+    pub fn countdown_remaining(&self, now_utc_milliseconds: i64) -> Option<u64> {
+        match self.mode {
+            TimedMode::Countdown if self.countdown_found.is_none() => {
+                let elapsed = self
+                    .countdown_started
+                    .map(|started| now_utc_milliseconds.saturating_sub(started).max(0) as u64)
+                    .unwrap_or_default();
+                Some(COUNTDOWN_MILLISECONDS.saturating_sub(elapsed))
+            }
+            TimedMode::Countdown => Some(0),
+            _ => None,
+        }
+    }
+
+    /// Begin the countdown if this is a countdown game which has not begun yet
+    pub fn with_countdown_started(&self, now_utc_milliseconds: i64) -> Self {
+        let mut result = self.clone();
+        if self.mode == TimedMode::Countdown && self.countdown_started.is_none() {
+            result.countdown_started = Some(now_utc_milliseconds);
+        }
+        result
+    }
+
+    /// Whether the game has ended and no more words can be found
+    pub fn is_over(&self) -> bool {
+        self.countdown_found.is_some()
+    }
+
+    /// End a countdown game
+    pub fn with_countdown_ended(&self, found: usize) -> Self {
+        Self {
+            countdown_found: Some(found),
+            ..self.clone()
+        }
+    }
+}
+
+/// The mode for new games
+#[derive(PartialEq, Eq, Clone, Copy, Serialize, Deserialize, Default, Store)]
+#[store(storage = "local")]
+pub struct TimedModeState {
+    pub mode: TimedMode,
+}
+
+/// Change the mode for new games.
+/// The current game also changes mode if nothing has been found yet.
+pub struct CycleTimedModeMsg;
+
+impl Reducer<TimedModeState> for CycleTimedModeMsg {
+    fn apply(self, state: Rc<TimedModeState>) -> Rc<TimedModeState> {
+        let mode = state.mode.next();
+
+        Dispatch::<FullGameState>::new().apply(|mut fs: Rc<FullGameState>| {
+            if fs.found_words.words.is_empty() {
+                let gs = Rc::make_mut(&mut fs);
+                gs.timed = TimedGame::new(mode);
+                gs.timing = GameTiming::started_now();
+            }
+            fs
+        });
+
+        TimedModeState { mode }.into()
+    }
+}
+
+/// End the countdown once time has run out
+pub struct CountdownExpiredMsg;
+
+impl Reducer<FullGameState> for CountdownExpiredMsg {
+    fn apply(self, mut state: Rc<FullGameState>) -> Rc<FullGameState> {
+        if state.timed.mode != TimedMode::Countdown || state.timed.is_over() {
+            return state;
+        }
+
+        //The game timing is left unfinished as not every number was found
+        let gs = Rc::make_mut(&mut state);
+        gs.timed = gs.timed.with_countdown_ended(gs.found_words.words.len());
+
+        Dispatch::<DialogState>::new()
+            .reduce_mut(|s| s.congratulations_dialog_type = Some(CongratsDialogType::TimeUp));
+
+        state
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_splits() {
+        let mut timed = TimedGame::new(TimedMode::TimeAttack);
+        for found in 1..=25 {
+            timed = timed.with_split(found, found as u64 * 1000);
+        }
+        timed = timed.with_split(20, 99_000);
+
+        assert_eq!(
+            timed.splits,
+            vec![
+                Split {
+                    found: 10,
+                    milliseconds: 10_000
+                },
+                Split {
+                    found: 20,
+                    milliseconds: 20_000
+                }
+            ]
+        );
+    }
+
+    #[test]
+    fn test_no_splits_when_relaxed() {
+        let timed = TimedGame::new(TimedMode::Relaxed).with_split(10, 1000);
+        assert!(timed.splits.is_empty());
+    }
+
+    #[test]
+    fn test_countdown() {
+        let timed = TimedGame::new(TimedMode::Countdown);
+        assert_eq!(
+            timed.countdown_remaining(999_999),
+            Some(COUNTDOWN_MILLISECONDS)
+        );
+
+        let timed = timed.with_countdown_started(1_000);
+        assert_eq!(timed.with_countdown_started(50_000), timed);
+        assert_eq!(
+            timed.countdown_remaining(1_000),
+            Some(COUNTDOWN_MILLISECONDS)
+        );
+        assert_eq!(timed.countdown_remaining(61_000), Some(240_000));
+        assert_eq!(timed.countdown_remaining(999_999), Some(0));
+        assert!(!timed.is_over());
+
+        let ended = timed.with_countdown_ended(42);
+        assert!(ended.is_over());
+        assert_eq!(ended.countdown_found, Some(42));
+        assert_eq!(ended.countdown_remaining(0), Some(0));
+
+        assert_eq!(TimedGame::default().countdown_remaining(0), None);
+        assert_eq!(
+            TimedGame::default()
+                .with_countdown_started(0)
+                .countdown_started,
+            None
+        );
+    }
+}
@@ -0,0 +1,172 @@
+use crate::state::prelude::*;
+use crate::state::user_state::UserState;
+use myriad::prelude::*;
+use std::rc::Rc;
+use yewdux::prelude::*;
+
+/// One scripted step of the tutorial
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TutorialStep {
+    /// A tiny board, padded with blanks
+    pub board: &'static str,
+    pub prompt: Text,
+    /// The number the player must make to finish the step
+    pub target: i32,
+}
+
+pub const TUTORIAL_STEPS: &[TutorialStep] = &[
+    TutorialStep {
+        board: "3+4______",
+        prompt: Text::TutorialFirstNumber,
+        target: 7,
+    },
+    TutorialStep {
+        board: "12+__3___",
+        prompt: Text::TutorialDigitsJoin,
+        target: 15,
+    },
+    TutorialStep {
+        board: "1___+___6",
+        prompt: Text::TutorialAdjacent,
+        target: 7,
+    },
+    TutorialStep {
+        board: "2+3_4×___",
+        prompt: Text::TutorialLeftToRight,
+        target: 20,
+    },
+    TutorialStep {
+        board: "5-_2×3___",
+        prompt: Text::TutorialOnYourOwn,
+        target: 9,
+    },
+];
+
+impl TutorialStep {
+    pub fn board(&self) -> Board<GRID_COLUMNS, GRID_ROWS, GRID_SIZE> {
+        Board::try_create(self.board).expect("Tutorial board should be valid")
+    }
+
+    /// Whether the chosen path makes the target number
+    pub fn is_complete(&self, positions: &[Tile<GRID_COLUMNS, GRID_ROWS>]) -> bool {
+        !positions.is_empty() && self.board().check(positions) == Ok(self.target)
+    }
+}
+
+/// The tutorial the player is working through.
+/// This is not saved, so the current game is never replaced by a tutorial board.
+#[derive(PartialEq, Clone, Default, Store)]
+pub struct TutorialState {
+    pub step: Option<usize>,
+}
+
+impl TutorialState {
+    pub fn is_active(&self) -> bool {
+        self.step.is_some()
+    }
+
+    pub fn current_step(&self) -> Option<&'static TutorialStep> {
+        self.step.and_then(|x| TUTORIAL_STEPS.get(x))
+    }
+
+    pub fn board(&self) -> Option<Board<GRID_COLUMNS, GRID_ROWS, GRID_SIZE>> {
+        self.current_step().map(|x| x.board())
+    }
+}
+
+/// The board being played, which is the tutorial board while the tutorial is active
+pub fn shown_board() -> Board<GRID_COLUMNS, GRID_ROWS, GRID_SIZE> {
+    Dispatch::<TutorialState>::new()
+        .get()
+        .board()
+        .unwrap_or_else(|| Dispatch::<FullGameState>::new().get().game.board.clone())
+}
+
+fn show_step() {
+    Dispatch::<ChosenPositionsState>::new().set(Default::default());
+    Dispatch::<RecentWordState>::new().reduce_mut(|s| s.recent_words.clear());
+}
+
+/// Show the first tutorial board in place of the current game
+pub struct StartTutorialMsg;
+
+impl Reducer<TutorialState> for StartTutorialMsg {
+    fn apply(self, state: Rc<TutorialState>) -> Rc<TutorialState> {
+        if state.is_active() {
+            return state;
+        }
+
+        show_step();
+
+        TutorialState { step: Some(0) }.into()
+    }
+}
+
+/// Move on to the next tutorial board, finishing after the last one
+pub struct NextTutorialStepMsg;
+
+impl Reducer<TutorialState> for NextTutorialStepMsg {
+    fn apply(self, state: Rc<TutorialState>) -> Rc<TutorialState> {
+        let Some(step) = state.step else {
+            return state;
+        };
+
+        if step + 1 < TUTORIAL_STEPS.len() {
+            show_step();
+            TutorialState {
+                step: Some(step + 1),
+            }
+            .into()
+        } else {
+            EndTutorialMsg { completed: true }.apply(state)
+        }
+    }
+}
+
+/// Leave the tutorial and go back to the current game
+pub struct EndTutorialMsg {
+    /// Whether the tutorial was finished or skipped, so it is not offered again
+    pub completed: bool,
+}
+
+impl Reducer<TutorialState> for EndTutorialMsg {
+    fn apply(self, state: Rc<TutorialState>) -> Rc<TutorialState> {
+        if self.completed {
+            Dispatch::<UserState>::new().reduce_mut(|s| s.tutorial_complete = true);
+        }
+        if !state.is_active() {
+            return state;
+        }
+
+        Dispatch::<ChosenPositionsState>::new().set(Default::default());
+
+        Default::default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tutorial_steps_can_be_completed() {
+        for step in TUTORIAL_STEPS {
+            let board = step.board();
+            let solution = board
+                .find_shortest(step.target, &[])
+                .unwrap_or_else(|| panic!("Cannot make {} on {}", step.target, step.board));
+            assert!(step.is_complete(&solution.path));
+        }
+    }
+
+    #[test]
+    fn test_tutorial_step_validation() {
+        let step = TUTORIAL_STEPS[0];
+        let tile = |x: u8| Tile::<GRID_COLUMNS, GRID_ROWS>::try_from_inner(x).unwrap();
+
+        assert!(step.is_complete(&[tile(0), tile(1), tile(2)]));
+        assert!(!step.is_complete(&[tile(0), tile(1)]));
+        assert!(!step.is_complete(&[tile(0)]));
+        assert!(!step.is_complete(&[]));
+    }
+}
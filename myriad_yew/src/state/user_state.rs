@@ -17,6 +17,9 @@ pub struct UserState {
     pub user_id1: Option<DeviceUUID>,
     pub ref_param: Option<String>,
     pub gclid_param: Option<String>,
+    /// Whether the tutorial has been finished or skipped
+    #[serde(default)]
+    pub tutorial_complete: bool,
 }
 
 #[derive(Default, Clone, PartialEq, Eq)]
@@ -61,7 +64,7 @@ impl UpdateParamsIfNewMessage {
 
             let message = EventLog {
                 user_id: device_id.clone(),
-
+                id: rand::random(),
                 event: LoggableEvent::NewUser {
                     device,
                     app,
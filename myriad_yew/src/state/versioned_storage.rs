@@ -0,0 +1,299 @@
+use serde::de::DeserializeOwned;
+use serde::*;
+use serde_json::Value;
+#[cfg(target_arch = "wasm32")]
+use std::rc::Rc;
+use yewdux::prelude::*;
+
+/// Upgrades saved data from one version to the next
+pub type Migration = fn(&mut Value);
+
+/// A store which is saved to local storage along with a schema version.
+/// Saves from older versions are upgraded by running each migration in turn.
+pub trait VersionedStore: Store + Serialize + DeserializeOwned + Default {
+    /// The current schema version. Saves without a version are version zero.
+    const VERSION: u32;
+
+    /// `MIGRATIONS[n]` upgrades data from version `n` to version `n + 1`
+    const MIGRATIONS: &'static [Migration];
+
+    fn from_json(json: &str) -> Result<Self, anyhow::Error> {
+        let value: Value = serde_json::from_str(json)?;
+        Self::from_value(value)
+    }
+
+    fn from_value(value: Value) -> Result<Self, anyhow::Error> {
+        let (version, mut data) = split_version(value);
+
+        if version > Self::VERSION {
+            anyhow::bail!(
+                "Save version {version} is newer than the supported version {}",
+                Self::VERSION
+            );
+        }
+
+        for migration in Self::MIGRATIONS
+            .iter()
+            .skip(version as usize)
+            .take((Self::VERSION - version) as usize)
+        {
+            migration(&mut data);
+        }
+
+        let state = serde_json::from_value(data)?;
+        Ok(state)
+    }
+
+    fn to_json(&self) -> Result<String, anyhow::Error> {
+        let json = serde_json::to_string(&SaveFile {
+            version: Self::VERSION,
+            data: self,
+        })?;
+        Ok(json)
+    }
+}
+
+#[derive(Serialize)]
+struct SaveFile<'a, T> {
+    version: u32,
+    data: &'a T,
+}
+
+/// Separate the version from the data. Data saved before versioning was introduced has no wrapper.
+/// Only an object with exactly a numeric `version` and a `data` key is a wrapper.
+fn split_version(value: Value) -> (u32, Value) {
+    let Value::Object(mut map) = value else {
+        return (0, value);
+    };
+    let version = map.get("version").and_then(|x| x.as_u64());
+
+    match version {
+        Some(version) if map.len() == 2 && map.contains_key("data") => {
+            (version as u32, map.remove("data").unwrap_or_default())
+        }
+        _ => (0, Value::Object(map)),
+    }
+}
+
+/// Use this in `Store::new` to load the state and keep it saved and synchronized between tabs
+#[cfg(target_arch = "wasm32")]
+pub fn init_versioned_store<S: VersionedStore>() -> S {
+    init_listener(VersionedStorageListener::<S>(Default::default()));
+
+    if let Err(err) = init_tab_sync::<S>() {
+        log::error!("Unable to init tab sync for storage: {:?}", err);
+    }
+
+    let json = match read::<S>() {
+        Ok(json) => json,
+        Err(err) => {
+            log::error!("Error reading state from storage: {:?}", err);
+            None
+        }
+    };
+
+    let (state, backup) = state_or_backup::<S>(json);
+
+    if let Some(backup) = backup {
+        if let Err(err) = get_storage().and_then(|mut x| save_backup::<S>(&mut x, &backup)) {
+            log::error!("Error backing up unreadable state: {:?}", err);
+        }
+    }
+
+    state
+}
+
+/// The state from a save, or the default state along with the save to back up if it could not be read.
+/// The default state is saved over the original, so a save from a newer version would otherwise be lost.
+#[cfg_attr(not(target_arch = "wasm32"), allow(dead_code))]
+fn state_or_backup<S: VersionedStore>(json: Option<String>) -> (S, Option<String>) {
+    let Some(json) = json else {
+        return (S::default(), None);
+    };
+
+    match S::from_json(&json) {
+        Ok(state) => (state, None),
+        Err(err) => {
+            log::error!("Error loading state from storage: {:?}", err);
+            (S::default(), Some(json))
+        }
+    }
+}
+
+#[cfg_attr(not(target_arch = "wasm32"), allow(dead_code))]
+fn backup_key<S: VersionedStore>() -> String {
+    format!("{}.backup", std::any::type_name::<S>())
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+pub fn init_versioned_store<S: VersionedStore>() -> S {
+    Default::default()
+}
+
+#[cfg(target_arch = "wasm32")]
+fn get_storage() -> Result<web_sys::Storage, anyhow::Error> {
+    web_sys::window()
+        .ok_or_else(|| anyhow::anyhow!("Window not found"))?
+        .local_storage()
+        .map_err(|err| anyhow::anyhow!("{err:?}"))?
+        .ok_or_else(|| anyhow::anyhow!("Local storage not available"))
+}
+
+/// Saves are stored under the type name so that saves from before versioning was introduced are found
+#[cfg(target_arch = "wasm32")]
+fn read<S: VersionedStore>() -> Result<Option<String>, anyhow::Error> {
+    get_storage()?
+        .get(std::any::type_name::<S>())
+        .map_err(|err| anyhow::anyhow!("{err:?}"))
+}
+
+#[cfg(target_arch = "wasm32")]
+fn load<S: VersionedStore>() -> Result<Option<S>, anyhow::Error> {
+    read::<S>()?.map(|json| S::from_json(&json)).transpose()
+}
+
+/// Somewhere saves can be kept by key
+trait KeyValueStorage {
+    fn get_item(&self, key: &str) -> Result<Option<String>, anyhow::Error>;
+    fn set_item(&mut self, key: &str, value: &str) -> Result<(), anyhow::Error>;
+}
+
+#[cfg(target_arch = "wasm32")]
+impl KeyValueStorage for web_sys::Storage {
+    fn get_item(&self, key: &str) -> Result<Option<String>, anyhow::Error> {
+        self.get(key).map_err(|err| anyhow::anyhow!("{err:?}"))
+    }
+
+    fn set_item(&mut self, key: &str, value: &str) -> Result<(), anyhow::Error> {
+        self.set(key, value)
+            .map_err(|err| anyhow::anyhow!("{err:?}"))
+    }
+}
+
+/// Keep the first unreadable save rather than replacing it with a later one
+#[cfg_attr(not(target_arch = "wasm32"), allow(dead_code))]
+fn save_backup<S: VersionedStore>(
+    storage: &mut impl KeyValueStorage,
+    json: &str,
+) -> Result<(), anyhow::Error> {
+    let key = backup_key::<S>();
+
+    if storage.get_item(&key)?.is_none() {
+        storage.set_item(&key, json)?;
+    }
+    Ok(())
+}
+
+#[cfg(target_arch = "wasm32")]
+fn save<S: VersionedStore>(state: &S) -> Result<(), anyhow::Error> {
+    let json = state.to_json()?;
+    get_storage()?
+        .set(std::any::type_name::<S>(), &json)
+        .map_err(|err| anyhow::anyhow!("{err:?}"))
+}
+
+#[cfg(target_arch = "wasm32")]
+fn init_tab_sync<S: VersionedStore>() -> Result<(), anyhow::Error> {
+    use wasm_bindgen::{prelude::Closure, JsCast};
+
+    let closure = Closure::wrap(Box::new(move |_: &web_sys::Event| match load::<S>() {
+        Ok(Some(state)) => {
+            Dispatch::<S>::new().set(state);
+        }
+        Err(err) => {
+            log::error!("Unable to load state: {:?}", err);
+        }
+        _ => {}
+    }) as Box<dyn FnMut(&web_sys::Event)>);
+
+    web_sys::window()
+        .ok_or_else(|| anyhow::anyhow!("Window not found"))?
+        .add_event_listener_with_callback("storage", closure.as_ref().unchecked_ref())
+        .map_err(|err| anyhow::anyhow!("{err:?}"))?;
+
+    closure.forget();
+
+    Ok(())
+}
+
+#[cfg(target_arch = "wasm32")]
+struct VersionedStorageListener<S>(std::marker::PhantomData<S>);
+
+#[cfg(target_arch = "wasm32")]
+impl<S: VersionedStore> Listener for VersionedStorageListener<S> {
+    type Store = S;
+
+    fn on_change(&mut self, state: Rc<Self::Store>) {
+        if let Err(err) = save(state.as_ref()) {
+            log::error!("Error saving state to storage: {:?}", err);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::history_state::HistoryState;
+    use std::collections::BTreeMap;
+
+    #[test]
+    fn test_newer_version_is_backed_up() {
+        let json = r#"{"version":9999,"data":{}}"#.to_string();
+
+        let (state, backup) = state_or_backup::<HistoryState>(Some(json.clone()));
+
+        assert!(state == HistoryState::default());
+        assert_eq!(backup, Some(json));
+    }
+
+    #[test]
+    fn test_readable_save_is_not_backed_up() {
+        let json = HistoryState::default().to_json().unwrap();
+
+        let (_, backup) = state_or_backup::<HistoryState>(Some(json));
+
+        assert_eq!(backup, None);
+    }
+
+    impl KeyValueStorage for BTreeMap<String, String> {
+        fn get_item(&self, key: &str) -> Result<Option<String>, anyhow::Error> {
+            Ok(self.get(key).cloned())
+        }
+
+        fn set_item(&mut self, key: &str, value: &str) -> Result<(), anyhow::Error> {
+            self.insert(key.to_string(), value.to_string());
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_failed_migration_is_backed_up() {
+        let mut storage = BTreeMap::new();
+        let first = r#"{"version":0,"data":{"games":"not a list"}}"#.to_string();
+        let second = r#"{"version":9999,"data":{}}"#.to_string();
+
+        for json in [first.clone(), second] {
+            let (_, backup) = state_or_backup::<HistoryState>(Some(json));
+            save_backup::<HistoryState>(&mut storage, &backup.unwrap()).unwrap();
+        }
+
+        assert_eq!(storage.len(), 1);
+        assert_eq!(
+            storage.get(&format!("{}.backup", std::any::type_name::<HistoryState>())),
+            Some(&first)
+        );
+    }
+
+    #[test]
+    fn test_split_version() {
+        let (version, data) = split_version(serde_json::json!({"version": 3, "data": [1]}));
+        assert_eq!(version, 3);
+        assert_eq!(data, serde_json::json!([1]));
+
+        let unversioned = serde_json::json!({"version": 3, "games": [1]});
+        assert_eq!(split_version(unversioned.clone()), (0, unversioned));
+
+        let unversioned = serde_json::json!({"version": "3", "data": [1]});
+        assert_eq!(split_version(unversioned.clone()), (0, unversioned));
+    }
+}
@@ -0,0 +1,77 @@
+use crate::state::prelude::*;
+use crate::web::prelude::{use_language, Route};
+use strum::IntoEnumIterator;
+use yew::prelude::*;
+use yew_router::prelude::use_navigator;
+use yewdux::prelude::*;
+
+#[function_component(AchievementsPage)]
+pub fn achievements_page() -> Html {
+    let navigator = use_navigator().unwrap();
+    let language = use_language();
+    let state = use_store_value::<AchievementsState>();
+
+    use_effect_with_deps(
+        |_| Dispatch::<AchievementsState>::new().apply(CheckAchievementsMsg),
+        (),
+    );
+
+    let onclick: Callback<MouseEvent> =
+        Callback::from(move |_me: MouseEvent| navigator.push(&Route::Home));
+
+    let rows = Achievement::iter()
+        .map(|achievement| {
+            let unlocked = state.unlocked.get(&achievement);
+            let status = match unlocked {
+                Some(date) => date.to_string(),
+                None => language.translate(Text::Locked).to_string(),
+            };
+            html!(<tr class={classes!(unlocked.is_none().then_some("achievement-locked"))}>
+                <td class="achievement-icon">{achievement.icon()}</td>
+                <td>
+                    <p class="achievement-name">{language.translate(achievement.name())}</p>
+                    <p class="achievement-description">{language.translate(achievement.description())}</p>
+                </td>
+                <td class="stats-value">{status}</td>
+            </tr>)
+        })
+        .collect::<Html>();
+
+    html!(<div class="history-page">
+        <p class="myriad-logo" {onclick}>{"ↂ"}</p>
+        <p class="page-header">{language.translate(Text::Achievements)}</p>
+        <br/>
+        <table class="history-table stats-table">
+            {rows}
+        </table>
+    </div>)
+}
+
+/// Checks for new achievements as numbers are found and announces any which are unlocked
+#[function_component(AchievementNotification)]
+pub fn achievement_notification() -> Html {
+    let language = use_language();
+    let found_count = *use_selector(|state: &FullGameState| state.found_words.words.len());
+    let unseen = *use_selector(|state: &AchievementsState| state.unseen.first().copied());
+
+    use_effect_with_deps(
+        |_| Dispatch::<AchievementsState>::new().apply(CheckAchievementsMsg),
+        found_count,
+    );
+
+    let Some(achievement) = unseen else {
+        return html!(<></>);
+    };
+
+    let onclick = Dispatch::<AchievementsState>::new().apply_callback(|_| DismissAchievementMsg);
+
+    html!(
+        <div class="achievement-notification" role="status" aria-live="polite" {onclick}>
+            <span class="achievement-icon">{achievement.icon()}</span>
+            <span>
+                <p class="achievement-description">{language.translate(Text::AchievementUnlocked)}</p>
+                <p class="achievement-name">{language.translate(achievement.name())}</p>
+            </span>
+        </div>
+    )
+}
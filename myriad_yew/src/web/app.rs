@@ -1,4 +1,5 @@
-use crate::state::preferences_state::DarkModeState;
+use crate::state::preferences_state::PreferencesState;
+use crate::state::user_state::UserState;
 use crate::state::prelude::*;
 use crate::web::found_words::*;
 use crate::web::prelude::*;
@@ -6,7 +7,7 @@ use wasm_bindgen_futures::spawn_local;
 use yew::prelude::*;
 use yew_hooks::use_effect_once;
 use yew_router::prelude::*;
-use yewdux::prelude::use_store;
+use yewdux::prelude::{use_selector, use_store};
 use yewdux::prelude::Dispatch;
 
 #[derive(Clone, Routable, PartialEq)]
@@ -18,17 +19,41 @@ pub enum Route {
     #[at("/history")]
     History,
 
+    #[at("/stats")]
+    Stats,
+
+    #[at("/calendar")]
+    Calendar,
+
     #[at("/game/:game")]
     Game { game: String },
 
     #[at("/cheat/:game")]
     Cheat { game: String },
+
+    #[at("/challenge/:game/:challenge")]
+    Challenge { game: String, challenge: String },
+
+    #[at("/replay/:game")]
+    Replay { game: String },
+
+    #[at("/race/:game")]
+    Race { game: String },
+
+    #[at("/tutorial")]
+    Tutorial,
+
+    #[at("/settings")]
+    Settings,
+
+    #[at("/achievements")]
+    Achievements,
 }
 
 #[function_component(App)]
 pub fn app() -> Html {
-    //Load the dark mode state here to make sure dark mode is set correctly
-    let _dms = use_store::<DarkModeState>();
+    //Load the preferences here to make sure the theme is set correctly
+    let _preferences = use_store::<PreferencesState>();
 
     use_effect_once(|| {
         spawn_local(crate::web::startup::setup());
@@ -45,11 +70,14 @@ pub fn app() -> Html {
 fn switch(route: Route) -> Html {
     match route {
         Route::Home => {
-            let game = Dispatch::<FullGameState>::new()
-                .get()
-                .game
-                .board
-                .canonical_string();
+            let dispatch = Dispatch::<FullGameState>::new();
+            let is_new = Dispatch::<HistoryState>::new().get().games.is_empty()
+                && dispatch.get().found_words.words.is_empty();
+            if is_new && !Dispatch::<UserState>::new().get().tutorial_complete {
+                return html! { <Redirect<Route> to={Route::Tutorial} /> };
+            }
+
+            let game = dispatch.get().game.board.canonical_string();
             html! { <Redirect<Route> to={Route::Game { game  }} /> }
         }
         Route::Game { game } => {
@@ -62,9 +90,45 @@ fn switch(route: Route) -> Html {
             html! { <MyriadApp {game} {cheat} />}
         }
 
+        Route::Challenge { game, challenge } => {
+            let cheat = false;
+            let challenge = Some(challenge);
+            html! { <MyriadApp {game} {cheat} {challenge} />}
+        }
+
+        Route::Replay { game } => {
+            html! { <ReplayPage {game} />}
+        }
+
+        Route::Race { game } => {
+            let cheat = false;
+            let race = true;
+            html! { <MyriadApp {game} {cheat} {race} />}
+        }
+
+        Route::Tutorial => {
+            html!( <TutorialPage/>)
+        }
+
         Route::History {} => {
             html!( <HistoryPage/>)
         }
+
+        Route::Stats {} => {
+            html!( <StatsPage/>)
+        }
+
+        Route::Calendar {} => {
+            html!( <CalendarPage/>)
+        }
+
+        Route::Settings {} => {
+            html!( <SettingsPage/>)
+        }
+
+        Route::Achievements {} => {
+            html!( <AchievementsPage/>)
+        }
     }
 }
 
@@ -72,6 +136,10 @@ fn switch(route: Route) -> Html {
 struct MyriadAppProps {
     pub game: String,
     pub cheat: bool,
+    #[prop_or_default]
+    pub challenge: Option<String>,
+    #[prop_or_default]
+    pub race: bool,
 }
 
 #[function_component(MyriadApp)]
@@ -81,11 +149,21 @@ fn myriad_app(props: &MyriadAppProps) -> Html {
     let game = Game::from_string(game_str.as_str());
 
     if let Some(game) = game {
+        let board = game.board.clone();
         Dispatch::new().apply(LoadGameMessage { game});
+
+        if let Some(challenge) = props.challenge.as_ref().and_then(|c| Challenge::decode(board.clone(), c)) {
+            Dispatch::<ChallengeState>::new().apply(SetChallengeMsg(challenge));
+        }
+
+        if props.race {
+            Dispatch::<RaceState>::new().apply(JoinRaceMsg { board });
+        }
     }
 
     let (width, height) = yew_hooks::use_window_size();
     let (width, height) = (width as f32, height as f32);
+    let left_handed = *use_selector(|state: &PreferencesState| state.left_handed);
 
     Dispatch::<GameSize>::new().apply(SetSizeMessage { width, height });
 
@@ -97,7 +175,7 @@ fn myriad_app(props: &MyriadAppProps) -> Html {
         );
     }
 
-    let size = GameSize::from_width_and_height(width, height);
+    let size = GameSize::from_width_and_height(width, height).with_left_handed(left_handed);
 
     let outer_container_style = size.outer_container_style();
     let container_style = size.container_style();
@@ -105,11 +183,13 @@ fn myriad_app(props: &MyriadAppProps) -> Html {
     html! {
         <>
         <CongratsDialog/>
+        <AchievementNotification/>
         <div class="outer-container"  style={outer_container_style}>
             <div class="container"   style={container_style}>
                 <Circles  />
                 <Crosshairs />
                 <InfoBar/>
+                <Announcer/>
                 <TabHeaders />
                 <AllFoundWords {cheat} />
 
@@ -123,7 +203,7 @@ fn myriad_app(props: &MyriadAppProps) -> Html {
     }
 }
 
-fn url_replace(s: &str) -> String {
+pub fn url_replace(s: &str) -> String {
     s.replace(' ', "+")
         .replace("%C3%B7", "÷")
         .replace("%C3%97", "×")
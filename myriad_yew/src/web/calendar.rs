@@ -0,0 +1,113 @@
+use crate::state::calendar::*;
+use crate::state::prelude::*;
+use crate::web::prelude::{use_language, Route};
+use chrono::{Datelike, NaiveDate};
+use yew::prelude::*;
+use yew_router::prelude::use_navigator;
+use yewdux::prelude::*;
+
+#[function_component(CalendarPage)]
+pub fn calendar_page() -> Html {
+    let navigator = use_navigator().unwrap();
+    let language = use_language();
+    let history = use_store_value::<HistoryState>();
+    let current_game = use_store_value::<FullGameState>();
+
+    let today = Game::get_today_date();
+    let shown_month = use_state(|| today);
+
+    let month = CalendarMonth::create(
+        *shown_month,
+        history.all_games_including_current(current_game.as_ref()),
+        today,
+    );
+
+    let onclick: Callback<MouseEvent> = {
+        let navigator = navigator.clone();
+        Callback::from(move |_me: MouseEvent| navigator.push(&Route::Home))
+    };
+
+    let month_button = |target: Option<NaiveDate>, text: &'static str| {
+        let shown_month = shown_month.clone();
+        let disabled = target.is_none();
+        let onclick = Callback::from(move |_me: MouseEvent| {
+            if let Some(target) = target {
+                shown_month.set(target);
+            }
+        });
+        html!(<button class="dialog-button calendar-month-button" {onclick} {disabled}>{text}</button>)
+    };
+
+    let previous_button = month_button(month.previous_month(), "←");
+    let next_button = month_button(month.next_month(today), "→");
+
+    let header = language
+        .weekday_names()
+        .into_iter()
+        .map(|day| html!(<th>{day}</th>))
+        .collect::<Html>();
+
+    let weeks = month
+        .weeks
+        .iter()
+        .map(|week| {
+            let days = week
+                .iter()
+                .map(|day| match day {
+                    Some(day) => html!(<CalendarDayCell day={*day} />),
+                    None => html!(<td></td>),
+                })
+                .collect::<Html>();
+            html!(<tr>{days}</tr>)
+        })
+        .collect::<Html>();
+
+    let title = format!(
+        "{} {}",
+        language.month_names()[month.first.month0() as usize],
+        month.first.year()
+    );
+
+    html!(<div class="history-page">
+        <p class="myriad-logo" {onclick}>{"ↂ"}</p>
+        <p class="page-header">{language.translate(Text::Calendar)}</p>
+        <p class="calendar-streaks">{language.translate_with(Text::CalendarStreaks, &[&language.format_integer(month.streaks.current as i64), &language.format_integer(month.streaks.longest as i64)])}</p>
+        <div class="calendar-month">
+            {previous_button}
+            <span class="calendar-month-title">{title}</span>
+            {next_button}
+        </div>
+        <table class="history-table calendar-table">
+            <tr>{header}</tr>
+            {weeks}
+        </table>
+    </div>)
+}
+
+#[derive(PartialEq, Properties)]
+pub struct CalendarDayProperties {
+    pub day: CalendarDay,
+}
+
+#[function_component(CalendarDayCell)]
+fn calendar_day_cell(properties: &CalendarDayProperties) -> Html {
+    let navigator = use_navigator().unwrap();
+    let day = properties.day;
+
+    let (class, title) = match day.status {
+        DayStatus::Unavailable => ("calendar-day-unavailable", String::new()),
+        DayStatus::NotPlayed => ("calendar-day-not-played", String::new()),
+        DayStatus::Played { found, total } => ("calendar-day-played", format!("{found} / {total}")),
+        DayStatus::Completed => ("calendar-day-completed", "💯".to_string()),
+    };
+
+    let disabled = day.status == DayStatus::Unavailable;
+    let date = day.date;
+    let onclick = Callback::from(move |_me: MouseEvent| {
+        crate::state::msg::move_to_daily_game(date, &navigator);
+    });
+
+    html!(<td>
+        <button class={classes!("calendar-day", class)} {title} {onclick} {disabled}>{date.day()}</button>
+    </td>)
+}
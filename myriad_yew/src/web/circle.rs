@@ -1,15 +1,27 @@
 use std::ops::Deref;
 
+use crate::state::i18n::Text;
+use crate::state::info_bar_state::InfoBarState;
 use crate::state::prelude::*;
 use crate::web::prelude::*;
+use itertools::Itertools;
 use myriad::prelude::*;
 use wasm_bindgen::JsCast;
 use web_sys::*;
 use yew::prelude::*;
 use yewdux::prelude::*;
 
+#[derive(PartialEq, Properties)]
+pub struct CirclesProperties {
+    /// Show the board without accepting any input
+    #[prop_or_default]
+    pub read_only: bool,
+}
+
 #[function_component(Circles)]
-pub fn circles() -> Html {
+pub fn circles(properties: &CirclesProperties) -> Html {
+    let read_only = properties.read_only;
+    let language = use_language();
     let circles = Tile::<GRID_COLUMNS, GRID_ROWS>::iter_by_row()
         .map(|coordinate| html!(< Circle {coordinate} />))
         .collect::<Html>();
@@ -24,14 +36,61 @@ pub fn circles() -> Html {
 
     let onpointerup = Dispatch::new().apply_callback(move |_: PointerEvent| InputMsg::Up {});
 
+    yew_hooks::use_event_with_window("keydown", move |ev: KeyboardEvent| {
+        if read_only || ev.alt_key() || ev.repeat() {
+            return;
+        }
+        if !is_board_key_target(ev.target()) {
+            return;
+        }
+        let msg = if ev.ctrl_key() || ev.meta_key() {
+            KeyboardMsg::from_shortcut(ev.key().as_str())
+        } else {
+            KeyboardMsg::from_key(ev.key().as_str())
+        };
+        if let Some(msg) = msg {
+            ev.prevent_default();
+            Dispatch::<KeyboardFocusState>::new().apply(msg);
+        }
+    });
+
+    let suggestions_enabled = *use_selector(|state: &SuggestionsState| state.enabled);
+    let positions = *use_selector(|state: &ChosenPositionsState| state.positions);
+    let board = use_shown_board();
+    let found_count = *use_selector(|state: &FullGameState| state.found_words.words.len());
+
+    use_effect_with_deps(
+        |_| {
+            Dispatch::<SuggestionsState>::new().apply(UpdateSuggestionsMsg);
+            || ()
+        },
+        (suggestions_enabled, positions, board, found_count),
+    );
+
     html! {
-      <div id="circles" class="circles" {onpointerup} {ontouchmove}>
+      <div id="circles" class={classes!("circles", read_only.then_some("circles-read-only"))} role="group" aria-label={language.translate(Text::BoardLabel)} {onpointerup} {ontouchmove}>
           {circles}
       </div>
 
     }
 }
 
+/// Keys are only handled on the page itself or on a board tile,
+/// so that buttons, text fields and other controls keep their own keyboard behaviour
+fn is_board_key_target(target: Option<EventTarget>) -> bool {
+    let Some(element) = target.and_then(|t| t.dyn_into::<Element>().ok()) else {
+        return false;
+    };
+    if TILES.contains_key(element.id().as_str()) {
+        return true;
+    }
+
+    window()
+        .and_then(|window| window.document())
+        .and_then(|document| document.body())
+        .is_some_and(|body| body.is_same_node(Some(&element)))
+}
+
 fn get_tile_from_touch_event(ev: TouchEvent) -> Option<Tile<GRID_COLUMNS, GRID_ROWS>> {
     let touch = ev.target_touches().item(0)?;
 
@@ -69,23 +128,45 @@ pub struct CircleProperties {
 #[function_component(Circle)]
 fn circle(properties: &CircleProperties) -> Html {
     let coordinate = properties.coordinate;
+    let language = use_language();
 
     let game_size = *use_store::<GameSize>().0.as_ref();
     let location = use_selector_with_deps(
         |state: &RotFlipState, (co, size)| state.get_location(co, size),
         (coordinate, game_size),
     );
+    let screen_position = *use_selector_with_deps(
+        |state: &RotFlipState, co| rotate_and_flip(co, state.rotate, state.flip),
+        coordinate,
+    )
+    .deref();
+
+    let board = use_shown_board();
+    let letter = board[coordinate];
 
-    let board = use_selector(|state: &FullGameState| state.game.board.clone());
+    let reachable = use_selector_with_deps(
+        |state: &SuggestionsState, co| {
+            state
+                .get_continuation(co)
+                .map(|x| x.results.iter().join(", "))
+        },
+        coordinate,
+    );
 
-    let circle_type = *use_selector_with_deps(
+    let circle_type = use_selector_with_deps(
         |state: &ChosenPositionsState, (co, board)| state.get_circle_type(co, board),
         (coordinate, board),
     )
+    .with_promising(reachable.is_some());
+
+    let is_hinted = *use_selector_with_deps(
+        |state: &InfoBarState, co| state.hinted_tile() == Some(*co),
+        coordinate,
+    )
     .deref();
 
-    let letter = *use_selector_with_deps(
-        |state: &FullGameState, co| state.game.board[*co],
+    let is_focused = *use_selector_with_deps(
+        |state: &KeyboardFocusState, co| state.focus == Some(*co),
         coordinate,
     )
     .deref();
@@ -105,6 +186,10 @@ fn circle(properties: &CircleProperties) -> Html {
 
     let onpointerup = Dispatch::new().apply_callback(move |_: PointerEvent| InputMsg::Up {});
 
+    let onfocus = Dispatch::<KeyboardFocusState>::new().reduce_mut_callback(move |s| {
+        s.focus = Some(coordinate);
+    });
+
     let square_radius = game_size.square_radius();
 
     let left = location.x - (square_radius * CIRCLE_RATIO);
@@ -124,17 +209,37 @@ fn circle(properties: &CircleProperties) -> Html {
     let circle_type_class = match circle_type {
         CircleType::Disabled => "circle-disabled",
         CircleType::LegalMove => "circle-legal",
+        CircleType::PromisingMove => "circle-legal circle-promising",
         CircleType::LastPosition => "circle-final",
         CircleType::IntermediatePosition { next: _ } => "circle-intermediate",
     };
 
-    let circle_classes = classes!("circle", circle_type_class);
+    let is_chosen = matches!(
+        circle_type,
+        CircleType::LastPosition | CircleType::IntermediatePosition { .. }
+    );
+    let is_disabled = matches!(circle_type, CircleType::Disabled);
+    let aria_label = language.translate_with(
+        Text::TileLabel,
+        &[
+            &spoken_rune(letter, language),
+            &(screen_position.y() + 1),
+            &(screen_position.x() + 1),
+        ],
+    );
+
+    let circle_classes = classes!(
+        "circle",
+        circle_type_class,
+        is_hinted.then_some("circle-hint"),
+        is_focused.then_some("circle-focused")
+    );
 
     html! {
         <div class="square" {key} style={g_style}>
 
-            <div id={circle_id} class={circle_classes} {onpointerdown} {onpointerup} {onpointerenter}>
-                <p id={text_id} class="circle-text"> {text} </p>
+            <div id={circle_id} class={circle_classes} title={reachable.as_ref().clone()} role="button" tabindex="0" aria-label={aria_label} aria-pressed={is_chosen.to_string()} aria-disabled={is_disabled.to_string()} {onfocus} {onpointerdown} {onpointerup} {onpointerenter}>
+                <p id={text_id} class="circle-text" aria-hidden="true"> {text} </p>
             </div>
         </div>
     }
@@ -1,5 +1,6 @@
 use crate::state::{prelude::*, game_rating::{GameRating, SuboptimalWord}};
-use chrono::Duration;
+use crate::state::preferences_state::PreferencesState;
+use crate::web::prelude::use_language;
 use myriad::prelude::{Board, FoundWord};
 use yew::prelude::*;
 use yew_router::prelude::use_navigator;
@@ -10,6 +11,7 @@ use yewdux::prelude::*;
 #[function_component(CongratsDialog)]
 pub fn congrats_dialog() -> Html {
     let navigator = use_navigator().unwrap();
+    let language = use_language();
     let (dialog_state, dispatch) = use_store::<DialogState>();
 
     let on_ok = dispatch.reduce_mut_callback(|state| state.congratulations_dialog_type = None);
@@ -26,47 +28,68 @@ pub fn congrats_dialog() -> Html {
     });
 
     let timing = use_selector(|state: &FullGameState| state.timing.clone());
+    let show_timer = *use_selector(|state: &PreferencesState| state.show_timer);
+    let hints_used = *use_selector(|state: &FullGameState| state.hints.total_hinted());
+    let timed = use_selector(|state: &FullGameState| state.timed.clone());
 
     let rating = use_selector(|state: &FullGameState| GameRating::create(state));
     let board = use_selector(|state: & FullGameState| state.game.board.clone());
+    let found_words = use_selector(|state: &FullGameState| state.found_words.clone());
+    let multiplayer = use_store_value::<MultiplayerState>();
+    let scores = match multiplayer.credits_for_board(&board) {
+        Some(credits) if !credits.is_empty() => multiplayer.scores(&found_words),
+        _ => vec![],
+    };
 
     if let Some(dialog_type) = dialog_state.congratulations_dialog_type {
-        let message: &str = match dialog_type {
-            CongratsDialogType::OneHundred => "Well done, you got 💯!",
+        let message: String = match dialog_type {
+            CongratsDialogType::OneHundred => language.translate(Text::WellDone100).to_string(),
+            CongratsDialogType::TimeUp => language.translate_with(
+                Text::TimeUp,
+                &[&timed.countdown_found.unwrap_or_default()],
+            ),
+            CongratsDialogType::Scoreboard => match winners(&scores).as_slice() {
+                [winner] => language.translate_with(Text::PlayerWins, &[&(winner + 1)]),
+                _ => language.translate(Text::Draw).to_string(),
+            },
         };
 
         let time_box = match *timing {
             GameTiming::Cheat => html!(<>
 
-                <p class="time-display">{"You Cheated!"}</p>
+                <p class="time-display">{language.translate(Text::YouCheated)}</p>
                  </>),
             GameTiming::Started { .. } | GameTiming::Unknown => html!(<></>),
-            GameTiming::Finished { total_milliseconds } => {
-                let total = Duration::milliseconds(total_milliseconds as i64);
-                if total.num_minutes() >= 100 {
-                    html!(<></>)
-                } else {
-                    let minutes = total.num_minutes();
-                    let seconds = total.num_seconds() - (60 * minutes);
-                    let time_string = format!("{minutes:02}:{seconds:02}");
-                    html!(<>
-                        <p class="time-display">{time_string}</p>
-                         </>)
-                }
-            }
+            GameTiming::Finished { .. } => match timing.time_string().filter(|_| show_timer) {
+                Some(time_string) => html!(<>
+                    <p class="time-display">{time_string}</p>
+                     </>),
+                None => html!(<></>),
+            },
         };
 
-        let rating_box = rating_box(&rating, &board);
+        let hints_box = match hints_used {
+            0 => html!(<></>),
+            1 => html!(<p class="hints-display">{language.translate(Text::HintUsed)}</p>),
+            n => html!(<p class="hints-display">{language.translate_with(Text::HintsUsed, &[&language.format_integer(n as i64)])}</p>),
+        };
+
+        let rating_box = rating_box(&rating, &board, language);
+        let splits_box = if show_timer { splits_box(&timed.splits) } else { html!(<></>) };
+        let scoreboard_box = scoreboard_box(&scores, language);
 
         html!(
             <dialog style="top: 25%" open={true}>
                     <p class="dialog-message">{message}</p>
+                    {scoreboard_box}
                     {time_box}
+                    {hints_box}
+                    {splits_box}
                     {rating_box}
                 <div class="dialog-buttons">
-                    <button class="dialog-button" onclick={on_ok}>{"Ok"}</button>
-                    <button class="dialog-button" onclick={on_share}>{"Share"}</button>
-                    <button class="dialog-button" onclick={on_new_game}>{"New Game"}</button>
+                    <button class="dialog-button" onclick={on_ok}>{language.translate(Text::Ok)}</button>
+                    <button class="dialog-button" onclick={on_share}>{language.translate(Text::Share)}</button>
+                    <button class="dialog-button" onclick={on_new_game}>{language.translate(Text::NewGame)}</button>
                 </div>
           </dialog>
         )
@@ -75,13 +98,58 @@ pub fn congrats_dialog() -> Html {
     }
 }
 
-fn rating_box(game_rating: &GameRating, board: &Board<GRID_ROWS, GRID_COLUMNS, GRID_SIZE>)->Html{
+fn splits_box(splits: &[Split]) -> Html {
+    if splits.is_empty() {
+        return html!(<></>);
+    }
+
+    let rows: Html = splits
+        .iter()
+        .map(|split| {
+            let time_string = GameTiming::Finished {
+                total_milliseconds: split.milliseconds,
+            }
+            .time_string()
+            .unwrap_or_default();
+            html!(<tr><td>{split.found}</td><td>{time_string}</td></tr>)
+        })
+        .collect();
+
+    html!(
+        <table class="splits-table">
+        {rows}
+        </table>
+    )
+}
+
+fn scoreboard_box(scores: &[usize], language: Language) -> Html {
+    if scores.is_empty() {
+        return html!(<></>);
+    }
+
+    let rows: Html = scores
+        .iter()
+        .enumerate()
+        .map(|(player, score)| {
+            let class = format!("scoreboard-player found-word-player-{player}");
+            html!(<tr><td {class}>{language.translate_with(Text::PlayerShort, &[&(player + 1)])}</td><td>{score}</td></tr>)
+        })
+        .collect();
+
+    html!(
+        <table class="scoreboard-table">
+        {rows}
+        </table>
+    )
+}
+
+fn rating_box(game_rating: &GameRating, board: &Board<GRID_ROWS, GRID_COLUMNS, GRID_SIZE>, language: Language)->Html{
 
     let score_box =
 
     if game_rating.actual_steps == game_rating.min_steps{
         html!(
-            <p class="score-display">{"Perfect Score"}</p>
+            <p class="score-display">{language.translate(Text::PerfectScore)}</p>
         )
     }
     else{
@@ -97,7 +165,7 @@ fn rating_box(game_rating: &GameRating, board: &Board<GRID_ROWS, GRID_COLUMNS, G
             <>
             <details>
                 <summary class="suboptimal-summary">
-                    {format!("{} Suboptimal {}", game_rating.suboptimal_words.len(), if game_rating.suboptimal_words.len() == 1 {"Solution"} else {"Solutions"})}
+                    {language.translate_with(if game_rating.suboptimal_words.len() == 1 {Text::SuboptimalSolution} else {Text::SuboptimalSolutions}, &[&game_rating.suboptimal_words.len()])}
                     <span class="icon">{"↓"}</span>
 
                 </summary>
@@ -120,7 +188,7 @@ fn rating_box(game_rating: &GameRating, board: &Board<GRID_ROWS, GRID_COLUMNS, G
             <>
             <details>
                 <summary class="hard-summary">
-                    {format!("{} Hard {}", game_rating.hard_words.len(), if game_rating.suboptimal_words.len() == 1 {"Solution"} else {"Solutions"})}
+                    {language.translate_with(if game_rating.hard_words.len() == 1 {Text::HardSolution} else {Text::HardSolutions}, &[&game_rating.hard_words.len()])}
                     <span class="icon">{"↓"}</span>
 
                 </summary>
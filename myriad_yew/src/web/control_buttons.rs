@@ -1,8 +1,7 @@
 use crate::state::full_game_state::FullGameState;
 use crate::state::game_size::*;
 use crate::state::msg;
-use crate::state::preferences_state::DarkModeNextMessage;
-use crate::state::preferences_state::DarkModeState;
+use crate::state::preferences_state::{DarkModeNextMessage, DarkModeState, PreferencesState};
 use crate::state::prelude::*;
 use crate::web::prelude::*;
 
@@ -16,11 +15,11 @@ pub struct GameButtonProperties {
 
     pub position_number: i32,
     pub size: f32,
-    pub orientation: Orientation,
 }
 
 #[function_component(TodayGameButton)]
 pub fn todays_game_button(properties: &GameButtonProperties) -> Html {
+    let language = use_language();
     let game_size = use_store_value::<GameSize>();
     let navigator = use_navigator().unwrap();
 
@@ -36,11 +35,12 @@ pub fn todays_game_button(properties: &GameButtonProperties) -> Html {
         false,
     );
 
-    html!(<ButtonBox id={"today_game_button"} text={"TODAY GAME"} {x} {y} size_units={properties.size}   orientation={properties.orientation}  {on_click} />)
+    html!(<ButtonBox id={"today_game_button"} text={language.translate(Text::Today)} {x} {y} size_units={properties.size}  {on_click} />)
 }
 
 #[function_component(RandomGameButton)]
 pub fn random_game_button(properties: &GameButtonProperties) -> Html {
+    let language = use_language();
     let game_size = use_store_value::<GameSize>();
     let navigator = use_navigator().unwrap();
     let on_click: Option<Callback<MouseEvent>> = Some(Callback::<MouseEvent>::from(move |_| {
@@ -53,7 +53,7 @@ pub fn random_game_button(properties: &GameButtonProperties) -> Html {
         false,
     );
 
-    html!(<ButtonBox id={"random_game_button"} text={"NEW RANDOM"} {x} {y} size_units={properties.size}   orientation={properties.orientation}  {on_click} />)
+    html!(<ButtonBox id={"random_game_button"} text={language.translate(Text::Random)} {x} {y} size_units={properties.size}  {on_click} />)
 }
 
 #[derive(PartialEq, Properties)]
@@ -61,7 +61,6 @@ pub struct ScoreCounterProperties {
     pub selected_tab: usize,
     pub position_number: i32,
     pub size: f32,
-    pub orientation: Orientation,
 }
 
 #[function_component(ScoreCounter)]
@@ -73,27 +72,19 @@ pub fn score_counter(properties: &ScoreCounterProperties) -> Html {
         false,
     );
 
+    let language = use_language();
     let (found, total) = *use_selector(|state: &FullGameState| state.get_found_count());
+    let score = *use_selector(|state: &FullGameState| state.score.total());
+    let board = use_selector(|state: &FullGameState| state.game.board.clone());
+    let challenge_found = *use_selector_with_deps(
+        |state: &ChallengeState, board| state.for_board(board).map(|x| x.found.len()),
+        board.as_ref().clone(),
+    );
     let found_pc = found * 100 / total;
-    let gradient_to = match properties.orientation {
-        Orientation::Vertical => "right",
-        Orientation::Horizontal => "top",
-    };
-    let gradient = format!("background: linear-gradient(to {gradient_to}, var(--progress) {found_pc}%, var(--progress-blank) {found_pc}%, var(--progress-blank));");
-
-    let width: f32;
-    let height: f32;
+    let gradient = format!("background: linear-gradient(to right, var(--progress) {found_pc}%, var(--progress-blank) {found_pc}%, var(--progress-blank));");
 
-    match properties.orientation {
-        Orientation::Vertical => {
-            width = FOUND_WORD_WIDTH * properties.size;
-            height = FOUND_WORD_HEIGHT;
-        }
-        Orientation::Horizontal => {
-            width = FOUND_WORD_WIDTH;
-            height = FOUND_WORD_HEIGHT * properties.size;
-        }
-    };
+    let width = FOUND_WORD_WIDTH * properties.size;
+    let height = FOUND_WORD_HEIGHT;
 
     // let width = format!("{}", FOUND_WORD_WIDTH * properties.width);
     // let height = format!("{FOUND_WORD_HEIGHT}");
@@ -106,6 +97,10 @@ pub fn score_counter(properties: &ScoreCounterProperties) -> Html {
         100 => format_number(found as i32),
         _ => format!("{} / {}", found, total),
     };
+    let text = match challenge_found {
+        Some(challenge_found) => format!("{text} vs {challenge_found}"),
+        None => format!("{text} · {}", language.format_integer(score as i64)),
+    };
 
     html!(
         <button {key} {style} {class} >
@@ -118,9 +113,9 @@ pub fn score_counter(properties: &ScoreCounterProperties) -> Html {
 pub fn dark_mode_button(properties: &GameButtonProperties) -> Html {
     use DarkModeState::*;
     let game_size = use_store_value::<GameSize>();
-    let dark_mode_state = use_store_value::<DarkModeState>();
+    let theme = *use_selector(|state: &PreferencesState| state.theme);
 
-    let text = match *dark_mode_state {
+    let text = match theme {
         Auto => "🌒",
         Light => "☀️",
         Dark => "🌑",
@@ -135,7 +130,26 @@ pub fn dark_mode_button(properties: &GameButtonProperties) -> Html {
         false,
     );
 
-    html!(<ButtonBox id={"dark_mode_button"} {text} {x} {y} size_units={properties.size}   orientation={properties.orientation} {on_click} />)
+    html!(<ButtonBox id={"dark_mode_button"} {text} {x} {y} size_units={properties.size} {on_click} />)
+}
+
+#[function_component(SuggestionsButton)]
+pub fn suggestions_button(properties: &GameButtonProperties) -> Html {
+    let game_size = use_store_value::<GameSize>();
+    let enabled = *use_selector(|state: &SuggestionsState| state.enabled);
+
+    let text = if enabled { "🧭" } else { "⊘" };
+
+    let on_click: Option<Callback<MouseEvent>> =
+        Some(Dispatch::new().apply_callback(|_| ToggleSuggestionsMsg));
+
+    let (x, y) = game_size.get_found_word_position(
+        properties.position_number,
+        properties.selected_tab,
+        false,
+    );
+
+    html!(<ButtonBox id={"suggestions_button"} {text} {x} {y} size_units={properties.size} {on_click} />)
 }
 
 #[function_component(RotateButton)]
@@ -153,7 +167,7 @@ pub fn rotate_button(properties: &GameButtonProperties) -> Html {
         false,
     );
 
-    html!(<ButtonBox id={"rotate_button"} text={"⟳"} {x} {y} size_units={properties.size}   orientation={properties.orientation} {on_click} />)
+    html!(<ButtonBox id={"rotate_button"} text={"⟳"} {x} {y} size_units={properties.size} {on_click} />)
 }
 
 #[function_component(FlipButton)]
@@ -171,7 +185,7 @@ pub fn flip_button(properties: &GameButtonProperties) -> Html {
         false,
     );
 
-    html!(<ButtonBox id={"flip_button"} text={"⬌"} {x} {y} size_units={properties.size}   orientation={properties.orientation}  {on_click} />)
+    html!(<ButtonBox id={"flip_button"} text={"⬌"} {x} {y} size_units={properties.size}  {on_click} />)
 }
 
 #[function_component(ShareButton)]
@@ -188,7 +202,131 @@ pub fn share_button(properties: &GameButtonProperties) -> Html {
         false,
     );
 
-    html!(<ButtonBox id={"share_button"} text={"⠪"} {x} {y} size_units={properties.size}   orientation={properties.orientation} {on_click} />)
+    html!(<ButtonBox id={"share_button"} text={"⠪"} {x} {y} size_units={properties.size} {on_click} />)
+}
+
+#[function_component(TimedModeButton)]
+pub fn timed_mode_button(properties: &GameButtonProperties) -> Html {
+    let game_size = use_store_value::<GameSize>();
+    let mode = use_selector(|state: &TimedModeState| state.mode);
+    let timed = use_selector(|state: &FullGameState| state.timed.clone());
+    let timing = *use_selector(|state: &FullGameState| state.timing);
+    let show_timer = *use_selector(|state: &PreferencesState| state.show_timer);
+    let update = yew_hooks::use_update();
+
+    let is_counting_down = timed.mode == TimedMode::Countdown && !timed.is_over();
+    let interval_timed = timed.clone();
+    yew_hooks::use_interval(
+        move || {
+            if interval_timed.countdown_remaining(now_utc_milliseconds()) == Some(0) {
+                Dispatch::<FullGameState>::new().apply(CountdownExpiredMsg);
+            }
+            update();
+        },
+        if is_counting_down && timed.countdown_started.is_some() {
+            1000
+        } else {
+            0
+        },
+    );
+
+    let text = match timed
+        .countdown_remaining(now_utc_milliseconds())
+        .filter(|_| is_counting_down && show_timer && matches!(timing, GameTiming::Started { .. }))
+    {
+        Some(remaining) => {
+            let remaining = remaining / 1000;
+            format!("{}:{:02}", remaining / 60, remaining % 60)
+        }
+        None => mode.icon().to_string(),
+    };
+
+    let on_click: Option<Callback<MouseEvent>> =
+        Some(Dispatch::new().apply_callback(|_| CycleTimedModeMsg));
+
+    let (x, y) = game_size.get_found_word_position(
+        properties.position_number,
+        properties.selected_tab,
+        false,
+    );
+
+    html!(<ButtonBox id={"timed_mode_button"} {text} {x} {y} size_units={properties.size} {on_click} />)
+}
+
+#[function_component(PlayersButton)]
+pub fn players_button(properties: &GameButtonProperties) -> Html {
+    let game_size = use_store_value::<GameSize>();
+    let multiplayer = use_store_value::<MultiplayerState>();
+
+    let text = if multiplayer.is_active() {
+        format!("P{}", multiplayer.current + 1)
+    } else {
+        "👤".to_string()
+    };
+
+    let on_click: Option<Callback<MouseEvent>> =
+        Some(Dispatch::new().apply_callback(|_| CyclePlayersMsg));
+
+    let (x, y) = game_size.get_found_word_position(
+        properties.position_number,
+        properties.selected_tab,
+        false,
+    );
+
+    let class = classes!(multiplayer
+        .is_active()
+        .then(|| format!("found-word-player-{}", multiplayer.current)));
+
+    html!(<ButtonBox id={"players_button"} {text} {x} {y} size_units={properties.size}  {on_click} {class} />)
+}
+
+#[function_component(UndoButton)]
+pub fn undo_button(properties: &GameButtonProperties) -> Html {
+    let game_size = use_store_value::<GameSize>();
+    let can_undo = *use_selector(|s: &FullGameState| !s.actions.undo.is_empty());
+    let on_click: Option<Callback<MouseEvent>> =
+        can_undo.then(|| Dispatch::new().apply_callback(|_| UndoRedoMsg::Undo));
+
+    let (x, y) = game_size.get_found_word_position(
+        properties.position_number,
+        properties.selected_tab,
+        false,
+    );
+
+    html!(<ButtonBox id={"undo_button"} text={"↶"} {x} {y} size_units={properties.size}  {on_click} />)
+}
+
+#[function_component(RedoButton)]
+pub fn redo_button(properties: &GameButtonProperties) -> Html {
+    let game_size = use_store_value::<GameSize>();
+    let can_redo = *use_selector(|s: &FullGameState| !s.actions.redo.is_empty());
+    let on_click: Option<Callback<MouseEvent>> =
+        can_redo.then(|| Dispatch::new().apply_callback(|_| UndoRedoMsg::Redo));
+
+    let (x, y) = game_size.get_found_word_position(
+        properties.position_number,
+        properties.selected_tab,
+        false,
+    );
+
+    html!(<ButtonBox id={"redo_button"} text={"↷"} {x} {y} size_units={properties.size}  {on_click} />)
+}
+
+#[function_component(CalendarButton)]
+pub fn calendar_button(properties: &GameButtonProperties) -> Html {
+    let game_size = use_store_value::<GameSize>();
+    let navigator = use_navigator().unwrap();
+
+    let on_click: Callback<MouseEvent> =
+        Callback::from(move |_me: MouseEvent| navigator.push(&Route::Calendar));
+
+    let (x, y) = game_size.get_found_word_position(
+        properties.position_number,
+        properties.selected_tab,
+        false,
+    );
+
+    html!(<ButtonBox id={"calendar_button"} text={"📅"} {x} {y} size_units={properties.size}  {on_click} />)
 }
 
 #[function_component(HistoryButton)]
@@ -205,7 +343,7 @@ pub fn history_button(properties: &GameButtonProperties) -> Html {
         false,
     );
 
-    html!(<ButtonBox id={"history_button"} text={"H"} {x} {y} size_units={properties.size}  {on_click} orientation={properties.orientation} />)
+    html!(<ButtonBox id={"history_button"} text={"H"} {x} {y} size_units={properties.size}  {on_click} />)
 }
 
 #[derive(PartialEq, Properties)]
@@ -215,8 +353,9 @@ pub struct ButtonBoxProperties {
     pub x: f32,
     pub y: f32,
     pub size_units: f32,
-    pub orientation: Orientation,
     pub on_click: Option<Callback<MouseEvent>>,
+    #[prop_or_default]
+    pub class: Classes,
 }
 
 #[function_component(ButtonBox)]
@@ -224,22 +363,8 @@ fn button_box(properties: &ButtonBoxProperties) -> Html {
     let x = properties.x;
     let y = properties.y;
 
-    let width: f32;
-    let height: f32;
-    let p_class: &'static str;
-
-    match properties.orientation {
-        Orientation::Vertical => {
-            width = FOUND_WORD_WIDTH * properties.size_units;
-            height = FOUND_WORD_HEIGHT;
-            p_class = "horizontal-writing";
-        }
-        Orientation::Horizontal => {
-            width = FOUND_WORD_WIDTH;
-            height = FOUND_WORD_HEIGHT * properties.size_units;
-            p_class = "vertical-writing"
-        }
-    };
+    let width = FOUND_WORD_WIDTH * properties.size_units;
+    let height = FOUND_WORD_HEIGHT;
 
     let style = format!("position:absolute; transform: translate({x}px, {y}px); height: {height}px; width: {width}px; border-radius:5px;");
 
@@ -249,13 +374,14 @@ fn button_box(properties: &ButtonBoxProperties) -> Html {
             Some("found-word-button")
         } else {
             None
-        }
+        },
+        properties.class.clone()
     );
     let key = properties.id.clone();
 
     html!(
         <button {key} {style} {class} onclick={properties.on_click.clone()}>
-            <p class={p_class}>
+            <p class="horizontal-writing">
             {properties.text.clone()}
             </p>
         </button>
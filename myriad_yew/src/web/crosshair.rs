@@ -1,4 +1,5 @@
 use crate::state::prelude::*;
+use crate::web::prelude::use_shown_board;
 use myriad::prelude::*;
 
 use std::ops::{Add, Deref};
@@ -28,9 +29,7 @@ const HALF_STOKE_WIDTH: f32 = 3.6;
 pub fn crosshair(properties: &CrossHairProperties) -> Html {
     let (game_size, _) = use_store::<GameSize>();
     let coordinate = properties.coordinate;
-    let board = use_selector(|state: &FullGameState| state.game.board.clone())
-        .deref()
-        .clone();
+    let board = use_shown_board();
 
     let circle_type = *use_selector_with_deps(
         |state: &ChosenPositionsState, (co, board)| state.get_circle_type(co, board),
@@ -45,6 +44,7 @@ pub fn crosshair(properties: &CrossHairProperties) -> Html {
     let line_classes = match circle_type {
         CircleType::Disabled => "crosshair invisible",
         CircleType::LegalMove => "crosshair invisible",
+        CircleType::PromisingMove => "crosshair invisible",
         CircleType::LastPosition => "crosshair",
         CircleType::IntermediatePosition { next: _ } => "crosshair crosshair-extended",
     };
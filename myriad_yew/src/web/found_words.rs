@@ -1,4 +1,3 @@
-use crate::state::info_bar_state::InfoBarSetMessage;
 use crate::state::selected_tab_state::SelectedTabState;
 
 use crate::state::prelude::*;
@@ -16,10 +15,35 @@ pub struct AllFoundWordsProperties {
 pub fn all_found_words(properties: &AllFoundWordsProperties) -> Html {
     let found_words = use_selector(|state: &FullGameState| state.found_words.clone());
     let difficulties = use_selector(|state: &FullGameState| state.game.difficulties.clone());
+    let board = use_selector(|state: &FullGameState| state.game.board.clone());
+    let challenge_found = use_selector_with_deps(
+        |state: &ChallengeState, board| {
+            state
+                .for_board(board)
+                .map(|x| x.found.clone())
+                .unwrap_or_default()
+        },
+        board.as_ref().clone(),
+    );
+    let credits = use_selector_with_deps(
+        |state: &MultiplayerState, board| state.credits_for_board(board).cloned().unwrap_or_default(),
+        board.as_ref().clone(),
+    );
+    let race_lost = use_selector_with_deps(
+        |state: &RaceState, board| {
+            state
+                .for_board(board)
+                .map(|x| {
+                    x.iter()
+                        .filter(|(_, finder)| **finder != state.name)
+                        .map(|(number, _)| *number)
+                        .collect::<Vec<_>>()
+                })
+                .unwrap_or_default()
+        },
+        board.as_ref().clone(),
+    );
     let selected_tab_state = use_store_value::<SelectedTabState>();
-    let orientation = use_selector(|gs: &GameSize| gs.orientation)
-        .as_ref()
-        .clone();
     let selected_tab = selected_tab_state.index;
 
     let cheat = properties.cheat;
@@ -33,7 +57,10 @@ pub fn all_found_words(properties: &AllFoundWordsProperties) -> Html {
         })
         .map(|(number, difficulty)| {
             let is_found = found_words.words.contains_key(&number);
-            html!(<FoundWordsWord {number}  {is_found} {selected_tab} {cheat} {difficulty}  />)
+            let is_challenge_found = challenge_found.contains(&number);
+            let player = credits.get(&number).copied().filter(|_| is_found);
+            let is_race_lost = race_lost.contains(&number);
+            html!(<FoundWordsWord {number}  {is_found} {is_challenge_found} {player} {is_race_lost} {selected_tab} {cheat} {difficulty}  />)
         })
         .collect::<Html>();
 
@@ -41,20 +68,26 @@ pub fn all_found_words(properties: &AllFoundWordsProperties) -> Html {
         <div class="found-words">
             {words}
 
-            <TodayGameButton {selected_tab}  size={6.0} position_number={101} {orientation}/>
+            <TodayGameButton {selected_tab}  size={3.0} position_number={101}/>
+            <PlayersButton {selected_tab} size={1.0} position_number={104}/>
+            <CalendarButton {selected_tab} size={1.0} position_number={105}/>
+            <SuggestionsButton {selected_tab} size={1.0} position_number={106}/>
 
-            <DarkModeButton  {selected_tab} size={1.0} {orientation} position_number={107}/>
-            <RotateButton  {selected_tab}  size={1.0} {orientation} position_number={108}/>
-            <FlipButton  {selected_tab}   size={1.0} {orientation} position_number={109}/>
-            <ShareButton {selected_tab}  size={1.0} {orientation} position_number={110} />
+            <DarkModeButton  {selected_tab} size={1.0} position_number={107}/>
+            <RotateButton  {selected_tab}  size={1.0} position_number={108}/>
+            <FlipButton  {selected_tab}   size={1.0} position_number={109}/>
+            <ShareButton {selected_tab}  size={1.0} position_number={110} />
 
-            <RandomGameButton {selected_tab}   size={6.0} {orientation} position_number={111}/>
-            <ScoreCounter {selected_tab}   size={3.0} {orientation} position_number={117}/>
+            <RandomGameButton {selected_tab}   size={3.0} position_number={111}/>
+            <UndoButton {selected_tab} size={1.0} position_number={114}/>
+            <RedoButton {selected_tab} size={1.0} position_number={115}/>
+            <TimedModeButton {selected_tab} size={1.0} position_number={116}/>
+            <ScoreCounter {selected_tab}   size={3.0} position_number={117}/>
 
 
 
 
-            <HistoryButton {selected_tab} size={1.0} {orientation} position_number={120}/>
+            <HistoryButton {selected_tab} size={1.0} position_number={120}/>
 
         </div>
     )
@@ -65,6 +98,12 @@ pub struct FoundWordProperties {
     pub number: i32,
     pub difficulty: Difficulty,
     pub is_found: bool,
+    /// Whether the player who sent the challenge found this number
+    pub is_challenge_found: bool,
+    /// The player who found this number in a multiplayer game
+    pub player: Option<usize>,
+    /// Whether another racer found this number first
+    pub is_race_lost: bool,
     pub selected_tab: usize,
     pub cheat: bool,
 }
@@ -81,10 +120,9 @@ pub fn found_words_word(properties: &FoundWordProperties) -> Html {
     let on_click: Option<Callback<MouseEvent>> = if properties.is_found || cheat {
         Some(Dispatch::new().apply_callback(move |_| FindNumberMsg { number, cheat }))
     } else {
-        Some(Dispatch::new().apply_callback(move |_| {
-            InfoBarSetMessage(crate::state::info_bar_state::InfoBarState::Difficulty(
-                difficulty,
-            ))
+        Some(Dispatch::new().apply_callback(move |_| UnfoundNumberClickedMsg {
+            number,
+            difficulty,
         }))
     };
 
@@ -100,7 +138,10 @@ pub fn found_words_word(properties: &FoundWordProperties) -> Html {
         "found-word",
         "found-word-button",
         "found-number",
-        properties.is_found.then_some(Some("found-word-complete"))
+        properties.is_found.then_some(Some("found-word-complete")),
+        properties.is_challenge_found.then_some("found-word-challenge"),
+        properties.player.map(|x| format!("found-word-player-{x}")),
+        properties.is_race_lost.then_some("found-word-race-lost")
     );
     html!(
         <button {key} {style} {class} onclick={on_click}>
@@ -1,5 +1,4 @@
-use crate::{state::{ prelude::*}, web::prelude::{Route, format_number}};
-use chrono::Duration;
+use crate::{state::prelude::*, web::prelude::{Route, format_number, HistoryTransfer, use_language}};
 use myriad::prelude::Board;
 use yew::prelude::*;
 use yew_router::prelude::use_navigator;
@@ -8,10 +7,31 @@ use yewdux::prelude::*;
 #[function_component(HistoryPage)]
 pub fn history_page() -> Html {
     let navigator = use_navigator().unwrap();
+    let language = use_language();
     let history = use_store_value::<HistoryState>();
 
     let current_game = use_store_value::<FullGameState>();
 
+    let on_stats_click: Callback<MouseEvent> = {
+        let navigator = navigator.clone();
+        Callback::from(move |_me:MouseEvent| navigator.push(&Route::Stats))
+    };
+
+    let on_tutorial_click: Callback<MouseEvent> = {
+        let navigator = navigator.clone();
+        Callback::from(move |_me:MouseEvent| navigator.push(&Route::Tutorial))
+    };
+
+    let on_achievements_click: Callback<MouseEvent> = {
+        let navigator = navigator.clone();
+        Callback::from(move |_me:MouseEvent| navigator.push(&Route::Achievements))
+    };
+
+    let on_settings_click: Callback<MouseEvent> = {
+        let navigator = navigator.clone();
+        Callback::from(move |_me:MouseEvent| navigator.push(&Route::Settings))
+    };
+
     let onclick: Callback<MouseEvent> =
     Callback::from(move |_me:MouseEvent| navigator.push(&Route::Home));
 
@@ -26,8 +46,13 @@ pub fn history_page() -> Html {
 
     html!(<div class="history-page">
         <p class="myriad-logo" {onclick}>{"ↂ"}</p>
-        <p class="page-header">{"History"}</p>
+        <p class="page-header">{language.translate(Text::History)}</p>
         <br/>
+      <button class="dialog-button stats-button" onclick={on_stats_click}>{language.translate(Text::Stats)}</button>
+      <button class="dialog-button stats-button" onclick={on_tutorial_click}>{language.translate(Text::Tutorial)}</button>
+      <button class="dialog-button stats-button" onclick={on_achievements_click}>{language.translate(Text::Achievements)}</button>
+      <button class="dialog-button stats-button" onclick={on_settings_click}>{language.translate(Text::Settings)}</button>
+      <HistoryTransfer />
       <table class="history-table">
       {rows}
       </table>
@@ -50,9 +75,22 @@ fn load_game(board: &Board<3,3,9>, navigator: &yew_router::prelude::Navigator){
 #[function_component(HistoryRow)]
 pub fn history_row(properties: &HistoryRowProperties) -> Html {
     let navigator = use_navigator().unwrap();
+    let language = use_language();
     let game = properties.state.game.clone();
-    let onclick: Callback<MouseEvent> =
-    Callback::from(move |_me:MouseEvent| load_game(&game.board, &navigator) );
+    let onclick: Callback<MouseEvent> = {
+        let navigator = navigator.clone();
+        Callback::from(move |_me:MouseEvent| load_game(&game.board, &navigator) )
+    };
+
+    let replay = if properties.state.events.is_empty() {
+        html!(<td></td>)
+    } else {
+        let game = properties.state.game.board.canonical_string();
+        let onclick: Callback<MouseEvent> = Callback::from(move |_me: MouseEvent| {
+            navigator.push(&Route::Replay { game: game.clone() })
+        });
+        html!(<td><button {onclick} class="button-text" title={language.translate(Text::Replay)}>{"▶"}</button></td>)
+    };
 
     let (found, total) = properties.state.get_found_count();
     let found_pc = found * 100 / total;
@@ -75,23 +113,35 @@ pub fn history_row(properties: &HistoryRowProperties) -> Html {
 
     let time = match properties.state.timing{
         GameTiming::Started { .. } => html!(<td></td>),
-        GameTiming::Finished { total_milliseconds } => {
-            let total = Duration::milliseconds(total_milliseconds as i64);
-                if total.num_minutes() >= 100 {
-                    html!(<></>)
-                } else {
-                    let minutes = total.num_minutes();
-                    let seconds = total.num_seconds() - (60 * minutes);
-                    let time_string = format!("{minutes:02}:{seconds:02}");
-                    html!(<td>
-                        <p class="history-time-display">{time_string}</p>
-                         </td>)
-                }
+        GameTiming::Finished { .. } => match properties.state.timing.time_string() {
+            Some(time_string) => html!(<td>
+                <p class="history-time-display">{time_string}</p>
+                 </td>),
+            None => html!(<></>),
         },
         GameTiming::Cheat => html!(<td><p class="history-time-display" >{"😈"}</p></td>),
         GameTiming::Unknown => html!(<td></td>),
     };
 
+    let timed = match (properties.state.timed.mode, properties.state.timed.countdown_found) {
+        (TimedMode::Relaxed, _) => html!(<td></td>),
+        (TimedMode::Countdown, Some(found)) => html!(<td><p class="history-time-display">{format!("⏳{found}")}</p></td>),
+        (mode, _) => html!(<td><p class="history-time-display">{mode.icon()}</p></td>),
+    };
+
+    let perfect = if properties.state.is_perfect() {
+        html!(<td><p class="history-time-display" title={language.translate(Text::PerfectGame)}>{"🎯"}</p></td>)
+    } else {
+        html!(<td></td>)
+    };
+
+    let score = html!(<td><p class="history-time-display" title={language.translate(Text::Score)}>{language.format_integer(properties.state.score.total() as i64)}</p></td>);
+
+    let hints = match properties.state.hints.total_hinted() {
+        0 => html!(<td></td>),
+        n => html!(<td><p class="history-hints-display">{format!("💡{n}")}</p></td>),
+    };
+
     html!(<tr>
       <td><button {onclick} class="button-text">{properties.state .game.board.to_single_string()}</button> </td>
     <td>
@@ -103,5 +153,10 @@ pub fn history_row(properties: &HistoryRowProperties) -> Html {
     </div>
     </td>
         {time}
+        {timed}
+        {perfect}
+        {score}
+        {hints}
+        {replay}
      </tr>)
 }
@@ -0,0 +1,157 @@
+use crate::state::history_code::*;
+use crate::state::prelude::*;
+use crate::state::versioned_storage::VersionedStore;
+use capacitor_bindings::clipboard::{Clipboard, WriteOptions};
+use wasm_bindgen::JsCast;
+use wasm_bindgen_futures::{spawn_local, JsFuture};
+use yew::prelude::*;
+use yewdux::prelude::*;
+
+const EXPORT_FILE_NAME: &str = "myriad_history.json";
+
+/// The history including the game currently being played
+fn exportable_history() -> HistoryState {
+    let history = Dispatch::<HistoryState>::new().get();
+    let current = Dispatch::<FullGameState>::new().get();
+
+    let mut games: Vec<FullGameState> = history
+        .all_games_including_current(current.as_ref())
+        .map(FullGameState::for_history)
+        .collect();
+    games.reverse();
+
+    HistoryState { games }
+}
+
+fn import(history: HistoryState) -> String {
+    let count = history.games.len();
+    Dispatch::<HistoryState>::new().apply(ImportHistoryMsg(history));
+    let language = current_language();
+    language.translate_with(
+        Text::ImportedGames,
+        &[&language.format_integer(count as i64)],
+    )
+}
+
+fn export_file() -> Result<(), anyhow::Error> {
+    let json = exportable_history().to_json()?;
+    let js_error = |err| anyhow::anyhow!("{err:?}");
+
+    let parts = js_sys::Array::of1(&json.into());
+    let mut options = web_sys::BlobPropertyBag::new();
+    options.type_("application/json");
+    let blob =
+        web_sys::Blob::new_with_str_sequence_and_options(&parts, &options).map_err(js_error)?;
+    let url = web_sys::Url::create_object_url_with_blob(&blob).map_err(js_error)?;
+
+    let document = web_sys::window()
+        .and_then(|w| w.document())
+        .ok_or_else(|| anyhow::anyhow!("Document not found"))?;
+    let anchor: web_sys::HtmlAnchorElement = document
+        .create_element("a")
+        .map_err(js_error)?
+        .unchecked_into();
+    anchor.set_href(&url);
+    anchor.set_download(EXPORT_FILE_NAME);
+    anchor.click();
+
+    web_sys::Url::revoke_object_url(&url).map_err(js_error)?;
+    Ok(())
+}
+
+async fn import_file(file: web_sys::File) -> Result<String, anyhow::Error> {
+    let text = JsFuture::from(file.text())
+        .await
+        .map_err(|err| anyhow::anyhow!("{err:?}"))?
+        .as_string()
+        .ok_or_else(|| anyhow::anyhow!("File is not text"))?;
+
+    let history = HistoryState::from_json(&text)?;
+    Ok(import(history))
+}
+
+async fn copy_code() -> Result<String, anyhow::Error> {
+    let code = encode_history_code(&exportable_history());
+    Clipboard::write(WriteOptions::builder().string(code).build())
+        .await
+        .map_err(|err| anyhow::anyhow!("{err}"))?;
+    Ok(current_language().translate(Text::CodeCopied).to_string())
+}
+
+async fn paste_code() -> Result<String, anyhow::Error> {
+    let code = Clipboard::read()
+        .await
+        .map_err(|err| anyhow::anyhow!("{err}"))?
+        .value;
+    let history = decode_history_code(&code)?;
+    Ok(import(history))
+}
+
+/// Buttons to move history between devices, either as a file or as a code on the clipboard
+#[function_component(HistoryTransfer)]
+pub fn history_transfer() -> Html {
+    let language = crate::web::prelude::use_language();
+    let message = use_state(String::new);
+
+    let show_result = {
+        let message = message.clone();
+        move |result: Result<String, anyhow::Error>| match result {
+            Ok(text) => message.set(text),
+            Err(err) => {
+                log::error!("{err:?}");
+                message.set(err.to_string());
+            }
+        }
+    };
+
+    let on_export_file = {
+        let show_result = show_result.clone();
+        Callback::from(move |_: MouseEvent| {
+            show_result(export_file().map(|_| {
+                current_language()
+                    .translate(Text::HistoryExported)
+                    .to_string()
+            }))
+        })
+    };
+
+    let on_copy_code = {
+        let show_result = show_result.clone();
+        Callback::from(move |_: MouseEvent| {
+            let show_result = show_result.clone();
+            spawn_local(async move { show_result(copy_code().await) });
+        })
+    };
+
+    let on_paste_code = {
+        let show_result = show_result.clone();
+        Callback::from(move |_: MouseEvent| {
+            let show_result = show_result.clone();
+            spawn_local(async move { show_result(paste_code().await) });
+        })
+    };
+
+    let on_import_file = Callback::from(move |e: Event| {
+        let input: web_sys::HtmlInputElement = e.target_unchecked_into();
+        let Some(file) = input.files().and_then(|files| files.get(0)) else {
+            return;
+        };
+        input.set_value("");
+
+        let show_result = show_result.clone();
+        spawn_local(async move { show_result(import_file(file).await) });
+    });
+
+    html!(
+        <div class="history-transfer">
+            <button class="dialog-button" onclick={on_export_file}>{language.translate(Text::Export)}</button>
+            <label class="dialog-button history-import-label">
+                {language.translate(Text::Import)}
+                <input type="file" accept=".json,application/json" class="history-import-input" onchange={on_import_file} />
+            </label>
+            <button class="dialog-button" onclick={on_copy_code}>{language.translate(Text::CopyCode)}</button>
+            <button class="dialog-button" onclick={on_paste_code}>{language.translate(Text::PasteCode)}</button>
+            <p class="history-transfer-message">{(*message).clone()}</p>
+        </div>
+    )
+}
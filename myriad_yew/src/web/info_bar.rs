@@ -1,13 +1,16 @@
 use crate::state::info_bar_state::InfoBarState;
+use crate::state::preferences_state::PreferencesState;
 use crate::state::prelude::*;
 use yew::function_component;
 use yew::prelude::*;
-use yewdux::prelude::use_store;
+use yewdux::prelude::{use_selector, use_store};
 
 #[function_component(InfoBar)]
 pub fn info_bar() -> Html {
     let (state, _) = use_store::<InfoBarState>();
     let (size, _) = use_store::<GameSize>();
+    let language = crate::web::prelude::use_language();
+    let difficulty_dots = *use_selector(|state: &PreferencesState| state.difficulty_dots);
 
     let color = state.text_color();
     let font_size = state.font_size();
@@ -15,22 +18,32 @@ pub fn info_bar() -> Html {
     let (x, y) = size.get_info_bar_position();
     let (width, height) = size.get_info_bar_size();
 
-    let orientation = match size.orientation {
-        Orientation::Vertical  => "horizontal-writing",
-        Orientation::Horizontal  => "vertical-writing",
-    };
-
-    let class = classes!("infobar-text", orientation );
+    let class = classes!("infobar-text", "horizontal-writing");
 
     let style = format!("transform: translate({x}px, {y}px); color: {color}; font-size: {font_size}; line-height: {line_height}; width: {width}px; height: {height}px; ");
 
 
     html! {
         <div class="infobar" >
-        <span {class} {style}>
-        {state.text()}
+        <span {class} {style} aria-hidden="true">
+        {state.display_text(difficulty_dots)}
+        </span>
+        <span class="visually-hidden" role="status" aria-live="polite" aria-atomic="true">
+        {state.spoken_text(language)}
         </span>
 
         </div>
     }
 }
+
+/// Speaks the result of each path to screen reader users
+#[function_component(Announcer)]
+pub fn announcer() -> Html {
+    let text = use_selector(|state: &AnnouncementState| state.live_text());
+
+    html! {
+        <div class="visually-hidden" role="alert" aria-live="assertive" aria-atomic="true">
+        {text.as_ref().clone()}
+        </div>
+    }
+}
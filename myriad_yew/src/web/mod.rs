@@ -1,4 +1,6 @@
+mod achievements;
 mod app;
+mod calendar;
 pub mod app_redirect;
 pub mod capacitor;
 mod circle;
@@ -9,13 +11,20 @@ mod congratulations;
 mod found_words;
 mod info_bar;
 mod recent_words;
+mod replay;
+mod settings;
 mod sharing;
+mod stats;
 mod startup;
 mod tab_headers;
+mod tutorial;
 pub mod history;
+mod history_transfer;
 pub mod prelude {
 
+    pub use crate::web::achievements::*;
     pub use crate::web::app::*;
+    pub use crate::web::calendar::*;
     pub use crate::web::circle::*;
     pub use crate::web::confetti::*;
     pub use crate::web::control_buttons::*;
@@ -24,9 +33,43 @@ pub mod prelude {
     pub use crate::web::found_words::*;
     pub use crate::web::info_bar::*;
     pub use crate::web::recent_words::*;
+    pub use crate::web::replay::*;
+    pub use crate::web::settings::*;
     pub use crate::web::startup::*;
+    pub use crate::web::stats::*;
     pub use crate::web::tab_headers::*;
+    pub use crate::web::tutorial::*;
     pub use crate::web::history::*;
+    pub use crate::web::history_transfer::*;
+
+    /// The language to show text in, updating when it changes
+    #[yew::hook]
+    pub fn use_language() -> crate::state::i18n::Language {
+        *yewdux::prelude::use_selector(
+            |state: &crate::state::preferences_state::PreferencesState| state.current_language(),
+        )
+    }
+
+    /// The board being played, which is the tutorial board while the tutorial is active
+    #[yew::hook]
+    pub fn use_shown_board() -> myriad::prelude::Board<
+        { crate::state::prelude::GRID_COLUMNS },
+        { crate::state::prelude::GRID_ROWS },
+        { crate::state::prelude::GRID_SIZE },
+    > {
+        let tutorial_board =
+            yewdux::prelude::use_selector(|state: &crate::state::prelude::TutorialState| {
+                state.board()
+            });
+        let game_board =
+            yewdux::prelude::use_selector(|state: &crate::state::prelude::FullGameState| {
+                state.game.board.clone()
+            });
+        tutorial_board
+            .as_ref()
+            .clone()
+            .unwrap_or_else(|| game_board.as_ref().clone())
+    }
 
     pub fn format_number(num: i32) -> String {
         if num == 100 {
@@ -0,0 +1,148 @@
+use crate::state::preferences_state::PreferencesState;
+use crate::state::prelude::*;
+use crate::web::prelude::*;
+use yew::prelude::*;
+use yew_router::prelude::use_navigator;
+use yewdux::prelude::*;
+
+/// How often the replay advances
+const TICK_MILLISECONDS: u64 = 100;
+
+#[derive(Debug, Properties, PartialEq, Clone)]
+pub struct ReplayPageProps {
+    pub game: String,
+}
+
+/// Replays the order in which numbers were found in a game
+#[function_component(ReplayPage)]
+pub fn replay_page(props: &ReplayPageProps) -> Html {
+    let navigator = use_navigator().unwrap();
+    let language = use_language();
+
+    use_effect_with_deps(
+        |game| {
+            if let Some(game) = Game::from_string(game.as_str()) {
+                Dispatch::new().apply(LoadGameMessage { game });
+            }
+            || Dispatch::<ChosenPositionsState>::new().set(Default::default())
+        },
+        url_replace(&props.game),
+    );
+
+    let events = use_selector(|state: &FullGameState| state.events.clone());
+    let replay = use_memo(|events| Replay::new(events), events.as_ref().clone());
+
+    let playback = use_state(|| 0u64);
+    let playing = use_state(|| true);
+
+    let duration = replay.duration_milliseconds();
+    {
+        let interval = if *playing {
+            TICK_MILLISECONDS as u32
+        } else {
+            0
+        };
+        let playback = playback.clone();
+        let playing = playing.clone();
+        yew_hooks::use_interval(
+            move || {
+                let next = (*playback + TICK_MILLISECONDS).min(duration);
+                playback.set(next);
+                if next >= duration {
+                    playing.set(false);
+                }
+            },
+            interval,
+        );
+    }
+
+    let shown = replay.steps_shown(*playback);
+    let current = shown
+        .checked_sub(1)
+        .and_then(|i| replay.steps.get(i))
+        .cloned();
+
+    use_effect_with_deps(
+        |current| {
+            let positions = current.as_ref().map(|x| x.word.path).unwrap_or_default();
+            Dispatch::<ChosenPositionsState>::new().set(ChosenPositionsState { positions });
+            || ()
+        },
+        current.clone(),
+    );
+
+    let on_restart = {
+        let playback = playback.clone();
+        let playing = playing.clone();
+        Callback::from(move |_: MouseEvent| {
+            playback.set(0);
+            playing.set(true);
+        })
+    };
+
+    let on_play_pause = {
+        let playback = playback.clone();
+        let playing = playing.clone();
+        Callback::from(move |_: MouseEvent| {
+            if !*playing && *playback >= duration {
+                playback.set(0);
+            }
+            playing.set(!*playing);
+        })
+    };
+
+    let on_next = {
+        let playback = playback.clone();
+        let playing = playing.clone();
+        let next = replay
+            .steps
+            .get(shown)
+            .map(|x| x.playback_milliseconds)
+            .unwrap_or(duration);
+        Callback::from(move |_: MouseEvent| {
+            playing.set(false);
+            playback.set(next);
+        })
+    };
+
+    let on_close = {
+        let game = props.game.clone();
+        Callback::from(move |_: MouseEvent| navigator.push(&Route::Game { game: game.clone() }))
+    };
+
+    let (width, height) = yew_hooks::use_window_size();
+    let (width, height) = (width as f32, height as f32);
+    let left_handed = *use_selector(|state: &PreferencesState| state.left_handed);
+
+    Dispatch::<GameSize>::new().apply(SetSizeMessage { width, height });
+
+    let size = GameSize::from_width_and_height(width, height).with_left_handed(left_handed);
+
+    let status = match current {
+        Some(step) => format!(
+            "{shown} / {} {} ⏱{}",
+            replay.steps.len(),
+            format_number(step.word.result),
+            step.time_string().unwrap_or_default()
+        ),
+        None if replay.steps.is_empty() => language.translate(Text::NothingToReplay).to_string(),
+        None => format!("0 / {}", replay.steps.len()),
+    };
+
+    html! {
+        <div class="outer-container" style={size.outer_container_style()}>
+            <div class="container" style={size.container_style()}>
+                <Circles read_only={true} />
+                <Crosshairs />
+                <InfoBar/>
+                <div class="replay-controls" style={size.controls_style()}>
+                    <p class="replay-status">{status}</p>
+                    <button class="dialog-button" onclick={on_restart}>{"⏮"}</button>
+                    <button class="dialog-button" onclick={on_play_pause}>{if *playing { "⏸" } else { "▶" }}</button>
+                    <button class="dialog-button" onclick={on_next}>{"⏭"}</button>
+                    <button class="dialog-button" onclick={on_close}>{"✕"}</button>
+                </div>
+            </div>
+        </div>
+    }
+}
@@ -0,0 +1,77 @@
+use crate::state::preferences_state::*;
+use crate::state::prelude::*;
+use crate::web::prelude::{use_language, Route};
+use strum::IntoEnumIterator;
+use yew::prelude::*;
+use yew_router::prelude::use_navigator;
+use yewdux::prelude::*;
+
+#[function_component(SettingsPage)]
+pub fn settings_page() -> Html {
+    let navigator = use_navigator().unwrap();
+    let language = use_language();
+    let theme = *use_selector(|state: &PreferencesState| state.theme);
+
+    let onclick: Callback<MouseEvent> =
+        Callback::from(move |_me: MouseEvent| navigator.push(&Route::Home));
+
+    let theme_text = match theme {
+        DarkModeState::Auto => Text::ThemeAuto,
+        DarkModeState::Light => Text::ThemeLight,
+        DarkModeState::Dark => Text::ThemeDark,
+    };
+    let on_theme_click =
+        Dispatch::<PreferencesState>::new().apply_callback(|_| DarkModeNextMessage);
+    let on_language_click =
+        Dispatch::<PreferencesState>::new().apply_callback(|_| CycleLanguageMsg);
+
+    let toggles = Preference::iter()
+        .map(|preference| html!(<PreferenceRow {preference} />))
+        .collect::<Html>();
+
+    html!(<div class="history-page">
+        <p class="myriad-logo" {onclick}>{"ↂ"}</p>
+        <p class="page-header">{language.translate(Text::Settings)}</p>
+        <br/>
+        <table class="history-table stats-table">
+            <tr>
+                <td>{language.translate(Text::Theme)}</td>
+                <td class="stats-value">
+                    <button class="dialog-button settings-button" onclick={on_theme_click}>{language.translate(theme_text)}</button>
+                </td>
+            </tr>
+            <tr>
+                <td>{language.translate(Text::Language)}</td>
+                <td class="stats-value">
+                    <button class="dialog-button settings-button" onclick={on_language_click}>{language.native_name()}</button>
+                </td>
+            </tr>
+            {toggles}
+        </table>
+    </div>)
+}
+
+#[derive(PartialEq, Properties)]
+struct PreferenceRowProperties {
+    preference: Preference,
+}
+
+#[function_component(PreferenceRow)]
+fn preference_row(properties: &PreferenceRowProperties) -> Html {
+    let language = use_language();
+    let preference = properties.preference;
+    let enabled = *use_selector(move |state: &PreferencesState| preference.is_enabled(state));
+
+    let onclick = Dispatch::<PreferencesState>::new()
+        .apply_callback(move |_| TogglePreferenceMsg(preference));
+    let text = if enabled { Text::On } else { Text::Off };
+
+    html!(<tr>
+        <td>{language.translate(preference.text())}</td>
+        <td class="stats-value">
+            <button class={classes!("dialog-button", "settings-button", enabled.then_some("settings-button-on"))} {onclick} aria-pressed={enabled.to_string()}>
+                {language.translate(text)}
+            </button>
+        </td>
+    </tr>)
+}
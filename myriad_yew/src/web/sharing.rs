@@ -3,7 +3,12 @@ use wasm_bindgen_futures::spawn_local;
 
 use yewdux::prelude::Dispatch;
 
-use crate::state::{full_game_state::FullGameState, prelude::LoggableEvent};
+use crate::state::{
+    challenge_state::Challenge, full_game_state::FullGameState, game_rating::GameRating,
+    i18n::{current_language, Text},
+    prelude::LoggableEvent,
+    share_text::share_text,
+};
 
 pub fn share() {
     spawn_local(async {
@@ -13,16 +18,23 @@ pub fn share() {
 
 pub async fn share_async() {
     let state = Dispatch::<FullGameState>::new().get();
+    let language = current_language();
     let game_text = state.game.board.canonical_string();
-    let url = format!("https://myriad-game.com/game/{game_text}");
+    let url = if state.found_words.words.is_empty() {
+        format!("https://myriad-game.com/game/{game_text}")
+    } else {
+        let route = Challenge::from_state(&state).route_path();
+        format!("https://myriad-game.com{route}")
+    };
+    let text = share_text(&state, &GameRating::create(&state), language);
 
     LoggableEvent::try_log_async(LoggableEvent::ClickShare).await;
 
     let result = capacitor_bindings::share::Share::share(ShareOptions {
         title: Some("Myriad".to_string()),
-        text: Some("Find every number from one to one hundred.".to_string()),
+        text: Some(text),
         url: Some(url),
-        dialog_title: Some("Myriad Share".to_string()),
+        dialog_title: Some(language.translate(Text::ShareDialogTitle).to_string()),
         files: None,
     })
     .await;
@@ -28,6 +28,8 @@ pub async fn get_url_search_params() -> Option<UrlSearchParams> {
 }
 
 pub async fn setup() {
+    track_sessions();
+
     Dispatch::<FailedLogsState>::new()
         .apply_future(ResentFailedLogsMessage)
         .await;
@@ -78,6 +80,32 @@ pub async fn setup() {
     }
 }
 
+/// Log how long the app was visible each time it is hidden
+fn track_sessions() -> Option<()> {
+    use wasm_bindgen::{prelude::Closure, JsCast};
+
+    start_session(now_utc_milliseconds());
+    let document = web_sys::window()?.document()?;
+
+    let closure = Closure::wrap(Box::new(move |_: &web_sys::Event| {
+        let hidden = web_sys::window()
+            .and_then(|w| w.document())
+            .map(|d| d.hidden())
+            .unwrap_or_default();
+        if hidden {
+            end_session(now_utc_milliseconds());
+        } else {
+            start_session(now_utc_milliseconds());
+        }
+    }) as Box<dyn FnMut(&web_sys::Event)>);
+
+    document
+        .add_event_listener_with_callback("visibilitychange", closure.as_ref().unchecked_ref())
+        .ok()?;
+    closure.forget();
+    Some(())
+}
+
 #[cfg(feature = "android")]
 /// Goes back, returns true if successful
 fn try_go_back() -> bool {
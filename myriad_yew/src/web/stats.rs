@@ -0,0 +1,99 @@
+use crate::state::prelude::*;
+use crate::state::stats::*;
+use crate::web::prelude::{use_language, Route};
+use myriad::prelude::Difficulty;
+use std::num::NonZeroU8;
+use yew::prelude::*;
+use yew_router::prelude::use_navigator;
+use yewdux::prelude::*;
+
+fn format_time(total_milliseconds: Option<u64>) -> String {
+    total_milliseconds
+        .and_then(|total_milliseconds| GameTiming::Finished { total_milliseconds }.time_string())
+        .unwrap_or_else(|| "-".to_string())
+}
+
+#[function_component(StatsPage)]
+pub fn stats_page() -> Html {
+    let navigator = use_navigator().unwrap();
+    let language = use_language();
+    let history = use_store_value::<HistoryState>();
+    let current_game = use_store_value::<FullGameState>();
+
+    let onclick: Callback<MouseEvent> =
+        Callback::from(move |_me: MouseEvent| navigator.push(&Route::Home));
+
+    let stats = GameStats::create(
+        history.all_games_including_current(current_game.as_ref()),
+        Game::get_today_date(),
+    );
+
+    let summary_rows = [
+        (
+            Text::GamesPlayed,
+            language.format_integer(stats.games_played as i64),
+        ),
+        (
+            Text::Completed,
+            format!(
+                "{} ({})",
+                language.format_integer(stats.games_completed as i64),
+                language.format_percent(stats.completion_percent() as i64)
+            ),
+        ),
+        (
+            Text::CurrentStreak,
+            language.format_integer(stats.streaks.current as i64),
+        ),
+        (
+            Text::LongestStreak,
+            language.format_integer(stats.streaks.longest as i64),
+        ),
+        (Text::AverageTime, format_time(stats.average_milliseconds)),
+        (Text::BestTime, format_time(stats.best_milliseconds)),
+    ]
+    .into_iter()
+    .map(|(name, value)| html!(<tr><td>{language.translate(name)}</td><td class="stats-value">{value}</td></tr>))
+    .collect::<Html>();
+
+    let find_rate_rows = stats
+        .find_rates
+        .iter()
+        .filter_map(|(length, rate)| {
+            let difficulty = Difficulty(NonZeroU8::new(*length)?);
+            let percent = rate.percent();
+            let style = format!("background: linear-gradient(to right, var(--progress) {percent}%, var(--progress-blank) {percent}%, var(--progress-blank));");
+            Some(html!(<tr>
+                <td>{difficulty.dots()}</td>
+                <td>
+                    <div class="history-progress-box">
+                        <div class="history-progress" {style}>{language.format_percent(percent as i64)}</div>
+                    </div>
+                </td>
+            </tr>))
+        })
+        .collect::<Html>();
+
+    let most_missed = stats
+        .most_missed
+        .iter()
+        .map(|(number, count)| html!(<tr><td>{number}</td><td class="stats-value">{format!("×{}", language.format_integer(*count as i64))}</td></tr>))
+        .collect::<Html>();
+
+    html!(<div class="history-page">
+        <p class="myriad-logo" {onclick}>{"ↂ"}</p>
+        <p class="page-header">{language.translate(Text::Statistics)}</p>
+        <br/>
+        <table class="history-table stats-table">
+            {summary_rows}
+        </table>
+        <p class="stats-header">{language.translate(Text::FoundByDifficulty)}</p>
+        <table class="history-table stats-table">
+            {find_rate_rows}
+        </table>
+        <p class="stats-header">{language.translate(Text::MostMissed)}</p>
+        <table class="history-table stats-table">
+            {most_missed}
+        </table>
+    </div>)
+}
@@ -0,0 +1,93 @@
+use crate::state::preferences_state::PreferencesState;
+use crate::state::prelude::*;
+use crate::web::prelude::*;
+use yew::prelude::*;
+use yew_hooks::use_effect_once;
+use yew_router::prelude::use_navigator;
+use yewdux::prelude::*;
+
+/// Teaches the rules on tiny boards, one step at a time
+#[function_component(TutorialPage)]
+pub fn tutorial_page() -> Html {
+    let navigator = use_navigator().unwrap();
+    let language = use_language();
+
+    use_effect_once(|| {
+        Dispatch::<TutorialState>::new().apply(StartTutorialMsg);
+        || Dispatch::<TutorialState>::new().apply(EndTutorialMsg { completed: false })
+    });
+
+    let step_index = *use_selector(|state: &TutorialState| state.step);
+    let step = use_selector(|state: &TutorialState| state.current_step().copied());
+    let positions = use_selector(|state: &ChosenPositionsState| state.positions);
+
+    let solved = step
+        .as_ref()
+        .map(|x| x.is_complete(&positions))
+        .unwrap_or_default();
+    let is_last = step_index
+        .map(|x| x + 1 >= TUTORIAL_STEPS.len())
+        .unwrap_or_default();
+
+    let on_next = {
+        let navigator = navigator.clone();
+        Callback::from(move |_: MouseEvent| {
+            Dispatch::<TutorialState>::new().apply(NextTutorialStepMsg);
+            if is_last {
+                navigator.push(&Route::Home);
+            }
+        })
+    };
+
+    let on_skip = Callback::from(move |_: MouseEvent| {
+        Dispatch::<TutorialState>::new().apply(EndTutorialMsg { completed: true });
+        navigator.push(&Route::Home);
+    });
+
+    let (width, height) = yew_hooks::use_window_size();
+    let (width, height) = (width as f32, height as f32);
+    let left_handed = *use_selector(|state: &PreferencesState| state.left_handed);
+
+    Dispatch::<GameSize>::new().apply(SetSizeMessage { width, height });
+
+    let size = GameSize::from_width_and_height(width, height).with_left_handed(left_handed);
+
+    let Some(step) = step.as_ref() else {
+        return html!(
+            <div class="outer-container">
+            <div class="container" />
+            </div>
+        );
+    };
+
+    let progress = format!(
+        "{} / {}",
+        step_index.unwrap_or_default() + 1,
+        TUTORIAL_STEPS.len()
+    );
+
+    let next = if solved {
+        html!(<button class="dialog-button" onclick={on_next}>{language.translate(if is_last { Text::Play } else { Text::Next })}</button>)
+    } else {
+        html!()
+    };
+
+    html! {
+        <div class="outer-container" style={size.outer_container_style()}>
+            <div class="container" style={size.container_style()}>
+                <Circles />
+                <Crosshairs />
+                <InfoBar/>
+                <Announcer/>
+                <div class="tutorial-controls" style={size.controls_style()}>
+                    <p class="tutorial-progress">{progress}</p>
+                    <p class={classes!("tutorial-prompt", solved.then_some("tutorial-prompt-solved"))}>
+                        {language.translate(if solved { Text::WellDone } else { step.prompt })}
+                    </p>
+                    {next}
+                    <button class="dialog-button" onclick={on_skip}>{language.translate(Text::Skip)}</button>
+                </div>
+            </div>
+        </div>
+    }
+}
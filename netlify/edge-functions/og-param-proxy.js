@@ -6,7 +6,8 @@ export default async (request, context) => {
   let page = await response.text();
 
   try {
-    const game = url.pathname.substring(6);
+    // Paths are either /game/:game or /challenge/:game/:challenge
+    const game = url.pathname.split("/")[2];
 
     page = page.replace(
       `https://myriad-game.com/icon/og_image_square.png`,
@@ -20,7 +21,7 @@ export default async (request, context) => {
 
     page = page.replace(
       `<meta property="og:url" content="https://myriad-game.com"`,
-      `<meta property="og:url" content="https://myriad-game.com/game/${game}"`
+      `<meta property="og:url" content="https://myriad-game.com${url.pathname}"`
     );
 
 
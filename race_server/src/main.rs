@@ -0,0 +1,172 @@
+mod room;
+
+use clap::Parser;
+use futures_util::{SinkExt, StreamExt};
+use myriad::race::*;
+use room::*;
+use std::collections::HashMap;
+use std::net::SocketAddr;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use tokio::net::{TcpListener, TcpStream};
+use tokio::sync::mpsc::{unbounded_channel, UnboundedSender};
+use tokio_tungstenite::tungstenite::Message;
+
+/// Host rooms where players race to find numbers on the same board
+#[derive(Parser, Debug)]
+#[command(author, version, about, long_about = None)]
+struct Args {
+    /// The address to listen on
+    #[arg(short, long, default_value = "127.0.0.1:9001")]
+    address: SocketAddr,
+}
+
+#[derive(Default)]
+struct Server {
+    rooms: HashMap<String, Room>,
+    senders: HashMap<PlayerId, UnboundedSender<Message>>,
+}
+
+type SharedServer = Arc<Mutex<Server>>;
+
+impl Server {
+    fn send(&self, id: PlayerId, message: &ServerMessage) {
+        let Some(sender) = self.senders.get(&id) else {
+            return;
+        };
+        match serde_json::to_string(message) {
+            Ok(text) => {
+                let _ = sender.send(Message::Text(text));
+            }
+            Err(err) => eprintln!("Could not serialize message: {err}"),
+        }
+    }
+
+    fn send_reply(&self, room: &str, id: PlayerId, reply: Reply) {
+        let others: Vec<PlayerId> = self
+            .rooms
+            .get(room)
+            .map(|x| x.players.keys().copied().filter(|x| *x != id).collect())
+            .unwrap_or_default();
+
+        if let Some(message) = reply.to_player.as_ref().or(reply.to_all.as_ref()) {
+            self.send(id, message);
+        }
+        if let Some(message) = reply.to_others.as_ref().or(reply.to_all.as_ref()) {
+            for other in others.iter() {
+                self.send(*other, message);
+            }
+        }
+    }
+
+    /// Handle a message from a player, returning the room they are now in
+    fn handle(
+        &mut self,
+        id: PlayerId,
+        room: Option<String>,
+        message: ClientMessage,
+    ) -> Option<String> {
+        match message {
+            ClientMessage::Join { board, name } => {
+                if let Some(previous) = room {
+                    self.leave(id, &previous);
+                }
+                let Some(board) = RaceBoard::try_create_canonical(&board) else {
+                    self.send(
+                        id,
+                        &ServerMessage::Rejected {
+                            reason: "Invalid board".to_string(),
+                        },
+                    );
+                    return None;
+                };
+                let key = board.canonical_string();
+                let reply = self
+                    .rooms
+                    .entry(key.clone())
+                    .or_insert_with(|| Room::new(board))
+                    .join(id, name);
+                self.send_reply(&key, id, reply);
+                Some(key)
+            }
+            ClientMessage::Submit { path } => {
+                let key = room?;
+                let reply = self.rooms.get_mut(&key)?.submit(id, &path);
+                self.send_reply(&key, id, reply);
+                Some(key)
+            }
+        }
+    }
+
+    fn leave(&mut self, id: PlayerId, key: &str) {
+        let Some(room) = self.rooms.get_mut(key) else {
+            return;
+        };
+        let reply = room.leave(id);
+        let empty = room.is_empty();
+        self.send_reply(key, id, reply);
+        if empty {
+            self.rooms.remove(key);
+        }
+    }
+}
+
+async fn handle_connection(server: SharedServer, id: PlayerId, stream: TcpStream) {
+    let websocket = match tokio_tungstenite::accept_async(stream).await {
+        Ok(websocket) => websocket,
+        Err(err) => {
+            eprintln!("Websocket handshake failed: {err}");
+            return;
+        }
+    };
+    let (mut outgoing, mut incoming) = websocket.split();
+    let (sender, mut receiver) = unbounded_channel();
+    server.lock().unwrap().senders.insert(id, sender);
+
+    let forward = tokio::spawn(async move {
+        while let Some(message) = receiver.recv().await {
+            if outgoing.send(message).await.is_err() {
+                break;
+            }
+        }
+    });
+
+    let mut room: Option<String> = None;
+    while let Some(Ok(message)) = incoming.next().await {
+        let Message::Text(text) = message else {
+            continue;
+        };
+        match serde_json::from_str::<ClientMessage>(&text) {
+            Ok(message) => room = server.lock().unwrap().handle(id, room.take(), message),
+            Err(err) => server.lock().unwrap().send(
+                id,
+                &ServerMessage::Rejected {
+                    reason: err.to_string(),
+                },
+            ),
+        }
+    }
+
+    let mut server = server.lock().unwrap();
+    if let Some(room) = room {
+        server.leave(id, &room);
+    }
+    server.senders.remove(&id);
+    forward.abort();
+}
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let args = Args::parse();
+    let listener = TcpListener::bind(args.address).await?;
+    println!("Listening on ws://{}", args.address);
+
+    let server = SharedServer::default();
+    let next_id = AtomicUsize::new(0);
+
+    loop {
+        let (stream, _) = listener.accept().await?;
+        let id = next_id.fetch_add(1, Ordering::Relaxed);
+        tokio::spawn(handle_connection(server.clone(), id, stream));
+    }
+}
@@ -0,0 +1,286 @@
+use myriad::prelude::*;
+use myriad::race::*;
+use std::collections::BTreeMap;
+
+pub type PlayerId = usize;
+
+pub type RaceBoard = Board<3, 3, 9>;
+
+/// Longer names are cut short
+pub const MAX_NAME_CHARS: usize = 20;
+
+/// What to send after a player does something
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Reply {
+    /// Sent only to the player
+    pub to_player: Option<ServerMessage>,
+    /// Sent to everyone else in the room
+    pub to_others: Option<ServerMessage>,
+    /// Sent to everyone in the room, including the player
+    pub to_all: Option<ServerMessage>,
+}
+
+/// Players racing to find numbers on one board
+#[derive(Debug, Clone)]
+pub struct Room {
+    pub board: RaceBoard,
+    pub solve_settings: SolveSettings,
+    pub players: BTreeMap<PlayerId, String>,
+    /// The player who found each number first
+    pub found: BTreeMap<i32, String>,
+}
+
+impl Room {
+    pub fn new(board: RaceBoard) -> Self {
+        Self {
+            board,
+            solve_settings: SolveSettings::default(),
+            players: Default::default(),
+            found: Default::default(),
+        }
+    }
+
+    pub fn board_string(&self) -> String {
+        self.board.canonical_string()
+    }
+
+    pub fn join(&mut self, id: PlayerId, name: String) -> Reply {
+        let name = self.unique_name(&name);
+        self.players.insert(id, name.clone());
+
+        Reply {
+            to_player: Some(ServerMessage::Joined {
+                board: self.board_string(),
+                name: name.clone(),
+                players: self.players.values().cloned().collect(),
+                found: self
+                    .found
+                    .iter()
+                    .map(|(number, name)| FirstFind {
+                        number: *number,
+                        name: name.clone(),
+                    })
+                    .collect(),
+            }),
+            to_others: Some(ServerMessage::PlayerJoined { name }),
+            to_all: None,
+        }
+    }
+
+    /// Trim and shorten a name, then number it if it belongs to anyone in the room or anyone who found a number.
+    /// Finds are kept by name so a new player must not take the name of one who left.
+    fn unique_name(&self, name: &str) -> String {
+        let name: String = name
+            .trim()
+            .chars()
+            .filter(|c| !c.is_control())
+            .take(MAX_NAME_CHARS)
+            .collect();
+        let name = match name.trim_end() {
+            "" => "Player",
+            name => name,
+        };
+
+        let is_taken = |candidate: &str| {
+            self.players.values().any(|x| x == candidate)
+                || self.found.values().any(|x| x == candidate)
+        };
+
+        if !is_taken(name) {
+            return name.to_string();
+        }
+        (2..)
+            .map(|number| format!("{name} {number}"))
+            .find(|candidate| !is_taken(candidate))
+            .expect("There are more numbers than names")
+    }
+
+    pub fn leave(&mut self, id: PlayerId) -> Reply {
+        match self.players.remove(&id) {
+            Some(name) => Reply {
+                to_others: Some(ServerMessage::PlayerLeft { name }),
+                ..Default::default()
+            },
+            None => Reply::default(),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.players.is_empty()
+    }
+
+    /// Check a submitted path and announce the number if nobody has found it yet
+    pub fn submit(&mut self, id: PlayerId, path: &[u8]) -> Reply {
+        let Some(name) = self.players.get(&id).cloned() else {
+            return Self::rejected("Not in this room");
+        };
+
+        let number = match self.check_path(path) {
+            Ok(number) => number,
+            Err(reason) => return Self::rejected(reason),
+        };
+
+        if self.found.contains_key(&number) {
+            return Reply::default();
+        }
+        self.found.insert(number, name.clone());
+
+        Reply {
+            to_all: Some(ServerMessage::Found(FirstFind { number, name })),
+            ..Default::default()
+        }
+    }
+
+    fn check_path(&self, path: &[u8]) -> Result<i32, &'static str> {
+        let tiles = path
+            .iter()
+            .map(|x| Tile::<3, 3>::try_from_inner(*x))
+            .collect::<Option<Vec<_>>>()
+            .ok_or("Invalid tile")?;
+
+        if tiles.is_empty() {
+            return Err("Empty path");
+        }
+        if tiles.windows(2).any(|x| !x[0].is_adjacent_to(&x[1])) {
+            return Err("Tiles are not adjacent");
+        }
+        if tiles
+            .iter()
+            .enumerate()
+            .any(|(i, tile)| tiles[..i].contains(tile))
+        {
+            return Err("Tile used twice");
+        }
+
+        let number = self.board.check(&tiles).map_err(|_| "Not a number")?;
+        if !self.solve_settings.allow(number) {
+            return Err("Number out of range");
+        }
+        Ok(number)
+    }
+
+    fn rejected(reason: &str) -> Reply {
+        Reply {
+            to_player: Some(ServerMessage::Rejected {
+                reason: reason.to_string(),
+            }),
+            ..Default::default()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn room() -> Room {
+        let board = RaceBoard::try_create_canonical("1+536-249").unwrap();
+        Room::new(board)
+    }
+
+    fn path_for(room: &Room, number: i32) -> Vec<u8> {
+        room.board
+            .find_shortest(number, &[])
+            .unwrap()
+            .path
+            .iter()
+            .map(|x| x.inner())
+            .collect()
+    }
+
+    #[test]
+    fn test_join_and_leave() {
+        let mut room = room();
+        room.join(0, "Alice".to_string());
+        let reply = room.join(1, "Bob".to_string());
+
+        assert_eq!(
+            reply.to_player,
+            Some(ServerMessage::Joined {
+                board: room.board_string(),
+                name: "Bob".to_string(),
+                players: vec!["Alice".to_string(), "Bob".to_string()],
+                found: vec![]
+            })
+        );
+        assert_eq!(
+            reply.to_others,
+            Some(ServerMessage::PlayerJoined {
+                name: "Bob".to_string()
+            })
+        );
+
+        let reply = room.leave(0);
+        assert_eq!(
+            reply.to_others,
+            Some(ServerMessage::PlayerLeft {
+                name: "Alice".to_string()
+            })
+        );
+        assert!(!room.is_empty());
+        assert_eq!(room.leave(0), Reply::default());
+    }
+
+    #[test]
+    fn test_names_are_cleaned_and_unique() {
+        let mut room = room();
+        let name = |reply: Reply| match reply.to_player {
+            Some(ServerMessage::Joined { name, .. }) => name,
+            _ => panic!("Expected to join"),
+        };
+
+        assert_eq!(name(room.join(0, "  Alice ".to_string())), "Alice");
+        assert_eq!(name(room.join(1, "Alice".to_string())), "Alice 2");
+        assert_eq!(name(room.join(2, " \t".to_string())), "Player");
+        assert_eq!(
+            name(room.join(3, "x".repeat(100))).chars().count(),
+            MAX_NAME_CHARS
+        );
+
+        room.submit(0, &path_for(&room, 7));
+        room.leave(0);
+        assert_eq!(name(room.join(4, "Alice".to_string())), "Alice 3");
+    }
+
+    #[test]
+    fn test_first_find_wins() {
+        let mut room = room();
+        room.join(0, "Alice".to_string());
+        room.join(1, "Bob".to_string());
+        let path = path_for(&room, 7);
+
+        let reply = room.submit(1, &path);
+        assert_eq!(
+            reply.to_all,
+            Some(ServerMessage::Found(FirstFind {
+                number: 7,
+                name: "Bob".to_string()
+            }))
+        );
+
+        assert_eq!(room.submit(0, &path), Reply::default());
+        assert_eq!(room.found.get(&7), Some(&"Bob".to_string()));
+
+        let reply = room.join(2, "Carol".to_string());
+        assert!(matches!(
+            reply.to_player,
+            Some(ServerMessage::Joined { found, .. }) if found.len() == 1
+        ));
+    }
+
+    #[test]
+    fn test_invalid_paths_are_rejected() {
+        let mut room = room();
+        room.join(0, "Alice".to_string());
+
+        let rejected =
+            |reply: Reply| matches!(reply.to_player, Some(ServerMessage::Rejected { .. }));
+
+        assert!(rejected(room.submit(0, &[])));
+        assert!(rejected(room.submit(0, &[0, 8])));
+        assert!(rejected(room.submit(0, &[0, 1, 0])));
+        assert!(rejected(room.submit(0, &[9])));
+        assert!(rejected(room.submit(1, &path_for(&room, 7))));
+        assert!(room.found.is_empty());
+    }
+}